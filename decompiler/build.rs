@@ -0,0 +1,130 @@
+//! Build-time code generator for the EVM `Opcode` enum.
+//!
+//! Reads the declarative `instructions.in` table and emits `opcodes.rs` into
+//! `OUT_DIR`, which `main.rs` pulls in with `include!`. Keeping the table in a
+//! flat file means adding a new opcode is a one-line edit with no hand-written
+//! `match` arms to keep in sync.
+
+use std::env;
+use std::fmt::Write as _;
+use std::fs;
+use std::path::Path;
+
+struct Entry {
+    mnemonic: String,
+    hex: u8,
+    arg_bytes: usize,
+    stack_in: usize,
+    stack_out: usize,
+    base_gas: u64,
+    category: String,
+}
+
+fn parse_table(src: &str) -> Vec<Entry> {
+    let mut entries = Vec::new();
+    for line in src.lines() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let cols: Vec<&str> = line.split_whitespace().collect();
+        assert!(
+            cols.len() == 7,
+            "instructions.in: expected 7 columns, got {} in line `{}`",
+            cols.len(),
+            line
+        );
+        let hex = u8::from_str_radix(cols[1].trim_start_matches("0x"), 16)
+            .unwrap_or_else(|_| panic!("instructions.in: bad hex `{}`", cols[1]));
+        entries.push(Entry {
+            mnemonic: cols[0].to_string(),
+            hex,
+            arg_bytes: cols[2].parse().expect("arg_bytes"),
+            stack_in: cols[3].parse().expect("stack_in"),
+            stack_out: cols[4].parse().expect("stack_out"),
+            base_gas: cols[5].parse().expect("base_gas"),
+            category: cols[6].to_string(),
+        });
+    }
+    entries
+}
+
+fn main() {
+    let manifest = env::var("CARGO_MANIFEST_DIR").unwrap();
+    let table_path = Path::new(&manifest).join("instructions.in");
+    println!("cargo:rerun-if-changed={}", table_path.display());
+
+    let src = fs::read_to_string(&table_path).expect("read instructions.in");
+    let entries = parse_table(&src);
+
+    let mut out = String::new();
+    out.push_str("// @generated by build.rs from instructions.in - do not edit by hand.\n\n");
+
+    // Enum definition.
+    out.push_str("#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]\n");
+    out.push_str("#[allow(non_camel_case_types, clippy::upper_case_acronyms)]\n");
+    out.push_str("pub enum Opcode {\n");
+    for e in &entries {
+        writeln!(out, "    {} = 0x{:02X},", e.mnemonic, e.hex).unwrap();
+    }
+    // `UNKNOWN` is distinct from the real `INVALID` (0xFE) opcode; it covers
+    // every byte value that is not assigned a mnemonic in the table.
+    out.push_str("    UNKNOWN = 0x100,\n");
+    out.push_str("}\n\n");
+
+    // From<u8>.
+    out.push_str("impl From<u8> for Opcode {\n");
+    out.push_str("    fn from(byte: u8) -> Self {\n");
+    out.push_str("        match byte {\n");
+    for e in &entries {
+        writeln!(out, "            0x{:02X} => Opcode::{},", e.hex, e.mnemonic).unwrap();
+    }
+    out.push_str("            _ => Opcode::UNKNOWN,\n");
+    out.push_str("        }\n    }\n}\n\n");
+
+    // Metadata accessors.
+    out.push_str("impl Opcode {\n");
+    emit_usize_accessor(&mut out, "arg_size", "how many immediate bytes follow this opcode", &entries, |e| e.arg_bytes, 0);
+    emit_usize_accessor(&mut out, "stack_inputs", "how many stack items this opcode consumes", &entries, |e| e.stack_in, 0);
+    emit_usize_accessor(&mut out, "stack_outputs", "how many stack items this opcode produces", &entries, |e| e.stack_out, 0);
+
+    // base_gas (u64).
+    out.push_str("    /// Static EVM base gas cost for this opcode (dynamic components excluded).\n");
+    out.push_str("    pub fn base_gas(&self) -> u64 {\n        match self {\n");
+    for e in &entries {
+        writeln!(out, "            Opcode::{} => {},", e.mnemonic, e.base_gas).unwrap();
+    }
+    out.push_str("            Opcode::UNKNOWN => 0,\n");
+    out.push_str("        }\n    }\n\n");
+
+    // category (&'static str).
+    out.push_str("    /// Coarse category tag from the instruction table.\n");
+    out.push_str("    pub fn category(&self) -> &'static str {\n        match self {\n");
+    for e in &entries {
+        writeln!(out, "            Opcode::{} => \"{}\",", e.mnemonic, e.category).unwrap();
+    }
+    out.push_str("            Opcode::UNKNOWN => \"unknown\",\n");
+    out.push_str("        }\n    }\n");
+    out.push_str("}\n");
+
+    let out_path = Path::new(&env::var("OUT_DIR").unwrap()).join("opcodes.rs");
+    fs::write(&out_path, out).expect("write opcodes.rs");
+}
+
+fn emit_usize_accessor(
+    out: &mut String,
+    name: &str,
+    doc: &str,
+    entries: &[Entry],
+    field: impl Fn(&Entry) -> usize,
+    unknown: usize,
+) {
+    writeln!(out, "    /// Returns {}.", doc).unwrap();
+    writeln!(out, "    pub fn {}(&self) -> usize {{", name).unwrap();
+    out.push_str("        match self {\n");
+    for e in entries {
+        writeln!(out, "            Opcode::{} => {},", e.mnemonic, field(e)).unwrap();
+    }
+    writeln!(out, "            Opcode::UNKNOWN => {},", unknown).unwrap();
+    out.push_str("        }\n    }\n\n");
+}
@@ -0,0 +1,55 @@
+/*
+ ═══════════════════════════════════════════════════════════════════════════════
+  SENTINEL SHIELD - Bytecode acquisition
+
+  Resolves a free-form "target" into raw runtime bytecode. The target may be a
+  hex bytecode string, a path to a file containing one, or a `0x…` contract
+  address to fetch over JSON-RPC. This mirrors heimdall's
+  `get_bytecode_from_target` so callers can hand us whatever they have.
+ ═══════════════════════════════════════════════════════════════════════════════
+*/
+
+use std::path::Path;
+
+use crate::rpc::{BytecodeFetcher, RpcClient};
+use crate::{DecompilerError, Result};
+
+/// Resolve `target` to runtime bytecode.
+///
+/// * a 20-byte `0x…` address is fetched via `eth_getCode` on the given chain
+///   (or `rpc_url` override);
+/// * an existing filesystem path is read and hex-decoded;
+/// * anything else is treated as an inline hex bytecode string.
+pub async fn get_bytecode_from_target(
+    target: &str,
+    chain: &str,
+    rpc_url: Option<&str>,
+) -> Result<Vec<u8>> {
+    let trimmed = target.trim();
+
+    if is_address(trimmed) {
+        let client = RpcClient::new(chain, rpc_url)?;
+        return client.get_code(trimmed).await;
+    }
+
+    if Path::new(trimmed).is_file() {
+        let contents = std::fs::read_to_string(trimmed)
+            .map_err(|e| DecompilerError::InvalidBytecode(e.to_string()))?;
+        return decode_hex(contents.trim());
+    }
+
+    decode_hex(trimmed)
+}
+
+/// A `0x`-prefixed 40-hex-digit string is an address.
+fn is_address(target: &str) -> bool {
+    target
+        .strip_prefix("0x")
+        .map(|rest| rest.len() == 40 && rest.bytes().all(|b| b.is_ascii_hexdigit()))
+        .unwrap_or(false)
+}
+
+fn decode_hex(s: &str) -> Result<Vec<u8>> {
+    let clean = s.strip_prefix("0x").unwrap_or(s);
+    hex::decode(clean).map_err(|e| DecompilerError::InvalidBytecode(e.to_string()))
+}
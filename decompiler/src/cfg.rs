@@ -0,0 +1,203 @@
+/*
+ ═══════════════════════════════════════════════════════════════════════════════
+  SENTINEL SHIELD - CFG views, Graphviz export, and pseudocode snapshots
+
+  The core `ControlFlowGraph` is a petgraph structure; this module renders it
+  into serializable / printable shapes: a flat node+successor view for the
+  output struct, a Graphviz `digraph`, and a minimal per-function pseudocode
+  snapshot grouping the blocks reachable from each dispatched selector.
+ ═══════════════════════════════════════════════════════════════════════════════
+*/
+
+use std::collections::HashSet;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{ControlFlowGraph, Instruction, Opcode};
+
+/// A single basic block, flattened for serialization.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CfgNode {
+    pub start_offset: usize,
+    pub end_offset: usize,
+    pub successors: Vec<usize>,
+    pub is_entry: bool,
+}
+
+/// A serializable snapshot of the control-flow graph.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct CfgView {
+    pub nodes: Vec<CfgNode>,
+    pub entry: Option<usize>,
+    pub unresolved_jumps: Vec<usize>,
+}
+
+impl CfgView {
+    pub fn from_graph(cfg: &ControlFlowGraph) -> Self {
+        let mut nodes: Vec<CfgNode> = cfg
+            .graph
+            .node_weights()
+            .map(|b| CfgNode {
+                start_offset: b.start_offset,
+                end_offset: b.end_offset,
+                successors: {
+                    let mut s = cfg.successors(b.start_offset);
+                    s.sort_unstable();
+                    s
+                },
+                is_entry: b.is_entry,
+            })
+            .collect();
+        nodes.sort_by_key(|n| n.start_offset);
+
+        CfgView {
+            nodes,
+            entry: cfg.entry.map(|e| cfg.graph[e].start_offset),
+            unresolved_jumps: cfg.unresolved_jumps.clone(),
+        }
+    }
+
+    /// Render the graph as Graphviz DOT.
+    pub fn to_dot(&self) -> String {
+        let mut out = String::from("digraph cfg {\n");
+        for node in &self.nodes {
+            out.push_str(&format!(
+                "    block_{0} [label=\"0x{0:x}-0x{1:x}\"];\n",
+                node.start_offset, node.end_offset
+            ));
+        }
+        for node in &self.nodes {
+            for succ in &node.successors {
+                out.push_str(&format!("    block_{} -> block_{};\n", node.start_offset, succ));
+            }
+        }
+        out.push_str("}\n");
+        out
+    }
+}
+
+/// A per-function approximation: the selector, the entry block it dispatches
+/// to, and the block offsets reachable from there.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FunctionSnapshot {
+    pub selector: String,
+    pub entry_offset: usize,
+    pub blocks: Vec<usize>,
+}
+
+/// Recover a minimal per-function snapshot by matching the Solidity dispatcher
+/// idiom (a `PUSH4 <selector>` near a `PUSHn <dest>; JUMPI`) and collecting the
+/// blocks reachable from each resolved entry.
+pub fn function_snapshots(
+    instructions: &[Instruction],
+    cfg: &ControlFlowGraph,
+) -> Vec<FunctionSnapshot> {
+    let jumpdests: HashSet<usize> = instructions
+        .iter()
+        .filter(|i| i.opcode == Opcode::JUMPDEST)
+        .map(|i| i.offset)
+        .collect();
+
+    let mut snapshots = Vec::new();
+
+    for (i, instr) in instructions.iter().enumerate() {
+        if instr.raw_byte != 0x63 {
+            continue; // PUSH4
+        }
+        let selector = match instr.arg_as_selector() {
+            Some(s) => s,
+            None => continue,
+        };
+
+        // Look ahead a short window for `PUSHn <dest>; JUMPI`.
+        let mut entry = None;
+        let window = instructions.iter().skip(i + 1).take(6);
+        let mut prev_push: Option<usize> = None;
+        for next in window {
+            if next.opcode == Opcode::JUMPI {
+                if let Some(dest) = prev_push {
+                    if jumpdests.contains(&dest) {
+                        entry = Some(dest);
+                    }
+                }
+                break;
+            }
+            prev_push = next.arg_as_u32().map(|v| v as usize);
+        }
+
+        if let Some(entry_offset) = entry {
+            snapshots.push(FunctionSnapshot {
+                selector,
+                entry_offset,
+                blocks: reachable(cfg, entry_offset),
+            });
+        }
+    }
+
+    snapshots
+}
+
+/// Per-function security facts recovered from the dispatcher: the selector, the
+/// `JUMPDEST` it branches to, the size of the reachable body, and whether that
+/// body can write storage or make an external call.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FunctionInfo {
+    pub selector: String,
+    pub entry_offset: usize,
+    pub block_count: usize,
+    pub has_state_write: bool,
+    pub makes_external_call: bool,
+}
+
+/// Recover per-function security facts from the dispatcher table. Each selector
+/// is mapped to the entry it dispatches to (via [`function_snapshots`]); the
+/// blocks reachable from that entry are then scanned for `SSTORE` and the
+/// external-call opcodes to attribute state writes and calls to the function.
+pub fn function_infos(
+    instructions: &[Instruction],
+    cfg: &ControlFlowGraph,
+) -> Vec<FunctionInfo> {
+    function_snapshots(instructions, cfg)
+        .into_iter()
+        .map(|snap| {
+            let mut has_state_write = false;
+            let mut makes_external_call = false;
+            for off in &snap.blocks {
+                if let Some(&node) = cfg.blocks.get(off) {
+                    for instr in &cfg.graph[node].instructions {
+                        match instr.opcode {
+                            Opcode::SSTORE => has_state_write = true,
+                            Opcode::CALL
+                            | Opcode::CALLCODE
+                            | Opcode::DELEGATECALL
+                            | Opcode::STATICCALL => makes_external_call = true,
+                            _ => {}
+                        }
+                    }
+                }
+            }
+            FunctionInfo {
+                selector: snap.selector,
+                entry_offset: snap.entry_offset,
+                block_count: snap.blocks.len(),
+                has_state_write,
+                makes_external_call,
+            }
+        })
+        .collect()
+}
+
+/// Block offsets reachable from `start` over the CFG.
+fn reachable(cfg: &ControlFlowGraph, start: usize) -> Vec<usize> {
+    let mut visited: HashSet<usize> = HashSet::new();
+    let mut queue = vec![start];
+    while let Some(off) = queue.pop() {
+        if !visited.insert(off) {
+            continue;
+        }
+        queue.extend(cfg.successors(off));
+    }
+    let mut out: Vec<usize> = visited.into_iter().collect();
+    out.sort_unstable();
+    out
+}
@@ -0,0 +1,296 @@
+/*
+ ═══════════════════════════════════════════════════════════════════════════════
+  SENTINEL SHIELD - EOF (EVM Object Format) container parsing
+
+  Detects and parses EOF containers (EIP-3540/4200/4750) so that modern
+  bytecode is not mis-read as legacy code. A container is split into its code
+  sections, which are disassembled independently, and a set of structural
+  invariants is checked.
+ ═══════════════════════════════════════════════════════════════════════════════
+*/
+
+use crate::{DecompilerError, Result};
+
+/// The EOF magic prefix: `0xEF00`.
+const MAGIC: [u8; 2] = [0xEF, 0x00];
+
+// Section kind markers.
+const KIND_TYPE: u8 = 0x01;
+const KIND_CODE: u8 = 0x02;
+const KIND_DATA: u8 = 0x04;
+const TERMINATOR: u8 = 0x00;
+
+// Relative-jump opcodes (EIP-4200), scanned as raw bytes.
+const RJUMP: u8 = 0xE0;
+const RJUMPI: u8 = 0xE1;
+
+// Legacy opcodes forbidden inside an EOF code section.
+const FORBIDDEN: &[(u8, &str)] = &[
+    (0x56, "JUMP"),
+    (0x57, "JUMPI"),
+    (0x58, "PC"),
+    (0xF2, "CALLCODE"),
+    (0xFF, "SELFDESTRUCT"),
+];
+
+/// Type metadata for a single code section (EIP-4750).
+#[derive(Debug, Clone)]
+pub struct TypeInfo {
+    pub inputs: u8,
+    pub outputs: u8,
+    pub max_stack_height: u16,
+}
+
+/// A parsed EOF container.
+#[derive(Debug, Clone)]
+pub struct EofContainer {
+    pub version: u8,
+    pub types: Vec<TypeInfo>,
+    pub code_sections: Vec<Vec<u8>>,
+    pub data: Vec<u8>,
+    /// Structural violations found during validation (empty when valid).
+    pub validation_errors: Vec<String>,
+}
+
+/// A unit of input bytecode, dispatched on its container format.
+pub enum Bytecode {
+    Legacy(Vec<u8>),
+    Eof(EofContainer),
+}
+
+impl Bytecode {
+    /// Classify raw bytes as legacy or EOF, parsing the container in the
+    /// latter case.
+    pub fn parse(bytecode: &[u8]) -> Result<Self> {
+        if bytecode.len() >= 2 && bytecode[..2] == MAGIC {
+            Ok(Bytecode::Eof(EofContainer::parse(bytecode)?))
+        } else {
+            Ok(Bytecode::Legacy(bytecode.to_vec()))
+        }
+    }
+}
+
+impl EofContainer {
+    /// Parse an EOF container, filling `validation_errors` with any structural
+    /// invariants that do not hold. Only hard errors that make the header
+    /// unparseable are returned as `Err`.
+    pub fn parse(bytes: &[u8]) -> Result<Self> {
+        let mut pos = 2; // skip magic
+        let version = *bytes
+            .get(pos)
+            .ok_or_else(|| DecompilerError::ParseError("EOF: missing version".into()))?;
+        pos += 1;
+
+        let mut type_size = 0usize;
+        let mut code_sizes: Vec<usize> = Vec::new();
+        let mut data_size = 0usize;
+
+        // Parse section headers until the terminator byte.
+        loop {
+            let kind = *bytes
+                .get(pos)
+                .ok_or_else(|| DecompilerError::ParseError("EOF: truncated header".into()))?;
+            pos += 1;
+            match kind {
+                TERMINATOR => break,
+                KIND_TYPE => {
+                    type_size = read_u16(bytes, pos)? as usize;
+                    pos += 2;
+                }
+                KIND_CODE => {
+                    let count = read_u16(bytes, pos)? as usize;
+                    pos += 2;
+                    for _ in 0..count {
+                        code_sizes.push(read_u16(bytes, pos)? as usize);
+                        pos += 2;
+                    }
+                }
+                KIND_DATA => {
+                    data_size = read_u16(bytes, pos)? as usize;
+                    pos += 2;
+                }
+                other => {
+                    return Err(DecompilerError::ParseError(format!(
+                        "EOF: unknown section kind 0x{:02x}",
+                        other
+                    )));
+                }
+            }
+        }
+
+        let mut errors = Vec::new();
+
+        // Type section: 4 bytes per code section.
+        let mut types = Vec::new();
+        let type_body = slice(bytes, pos, type_size);
+        if type_size % 4 != 0 {
+            errors.push(format!("type section size {} is not a multiple of 4", type_size));
+        }
+        for chunk in type_body.chunks_exact(4) {
+            types.push(TypeInfo {
+                inputs: chunk[0],
+                outputs: chunk[1],
+                max_stack_height: u16::from_be_bytes([chunk[2], chunk[3]]),
+            });
+        }
+        pos += type_size;
+
+        if !code_sizes.is_empty() && types.len() != code_sizes.len() {
+            errors.push(format!(
+                "{} code sections but {} type entries",
+                code_sizes.len(),
+                types.len()
+            ));
+        }
+
+        // Code sections.
+        let mut code_sections = Vec::new();
+        for (i, &size) in code_sizes.iter().enumerate() {
+            let section = slice(bytes, pos, size);
+            if section.len() != size {
+                errors.push(format!(
+                    "code section {}: declared {} bytes, found {}",
+                    i,
+                    size,
+                    section.len()
+                ));
+            }
+            validate_section(i, &section, &mut errors);
+            code_sections.push(section.to_vec());
+            pos += size;
+        }
+
+        // Data section.
+        let data = slice(bytes, pos, data_size).to_vec();
+        if data.len() != data_size {
+            errors.push(format!(
+                "data section: declared {} bytes, found {}",
+                data_size,
+                data.len()
+            ));
+        }
+
+        Ok(EofContainer {
+            version,
+            types,
+            code_sections,
+            data,
+            validation_errors: errors,
+        })
+    }
+
+    pub fn is_valid(&self) -> bool {
+        self.validation_errors.is_empty()
+    }
+}
+
+/// Check per-section invariants: forbidden legacy opcodes and in-bounds
+/// relative jump targets. PUSH immediates are skipped so their data bytes are
+/// not mistaken for opcodes.
+fn validate_section(index: usize, code: &[u8], errors: &mut Vec<String>) {
+    let mut i = 0;
+    while i < code.len() {
+        let op = code[i];
+        if let Some((_, name)) = FORBIDDEN.iter().find(|(b, _)| *b == op) {
+            errors.push(format!("code section {}: forbidden opcode {} at {}", index, name, i));
+        }
+        match op {
+            RJUMP | RJUMPI => {
+                if i + 2 >= code.len() {
+                    errors.push(format!(
+                        "code section {}: truncated relative jump at {}",
+                        index, i
+                    ));
+                    break;
+                }
+                let rel = i16::from_be_bytes([code[i + 1], code[i + 2]]);
+                // Target is relative to the instruction following the 2-byte immediate.
+                let target = (i as isize) + 3 + rel as isize;
+                if target < 0 || target as usize >= code.len() {
+                    errors.push(format!(
+                        "code section {}: relative jump at {} lands outside section",
+                        index, i
+                    ));
+                }
+                i += 3;
+            }
+            // Skip PUSH1..PUSH32 immediates.
+            0x60..=0x7F => {
+                i += 1 + (op - 0x5F) as usize;
+            }
+            _ => i += 1,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A minimal valid single-section container: `PUSH1 0x00; STOP`.
+    fn valid_container() -> Vec<u8> {
+        vec![
+            0xEF, 0x00, 0x01, // magic + version
+            0x01, 0x00, 0x04, // type section, 4 bytes
+            0x02, 0x00, 0x01, 0x00, 0x03, // code section: 1 section of 3 bytes
+            0x04, 0x00, 0x00, // data section, 0 bytes
+            0x00, // terminator
+            0x00, 0x00, 0x00, 0x00, // type body
+            0x60, 0x00, 0x00, // code body
+        ]
+    }
+
+    #[test]
+    fn parses_valid_container() {
+        let container = match Bytecode::parse(&valid_container()).unwrap() {
+            Bytecode::Eof(c) => c,
+            Bytecode::Legacy(_) => panic!("magic should classify as EOF"),
+        };
+        assert_eq!(container.version, 1);
+        assert_eq!(container.types.len(), 1);
+        assert_eq!(container.code_sections, vec![vec![0x60, 0x00, 0x00]]);
+        assert!(container.is_valid(), "{:?}", container.validation_errors);
+    }
+
+    #[test]
+    fn legacy_bytecode_is_not_eof() {
+        match Bytecode::parse(&[0x60, 0x00, 0x00]).unwrap() {
+            Bytecode::Legacy(code) => assert_eq!(code, vec![0x60, 0x00, 0x00]),
+            Bytecode::Eof(_) => panic!("no magic should classify as legacy"),
+        }
+    }
+
+    #[test]
+    fn forbidden_opcode_is_flagged() {
+        let bytes = vec![
+            0xEF, 0x00, 0x01, // magic + version
+            0x01, 0x00, 0x04, // type section
+            0x02, 0x00, 0x01, 0x00, 0x01, // one code section of 1 byte
+            0x04, 0x00, 0x00, // data
+            0x00, // terminator
+            0x00, 0x00, 0x00, 0x00, // type body
+            0x56, // code body: forbidden JUMP
+        ];
+        let container = EofContainer::parse(&bytes).unwrap();
+        assert!(!container.is_valid());
+        assert!(container.validation_errors.iter().any(|e| e.contains("JUMP")));
+    }
+
+    #[test]
+    fn truncated_header_errors() {
+        assert!(EofContainer::parse(&[0xEF, 0x00]).is_err());
+    }
+}
+
+fn read_u16(bytes: &[u8], pos: usize) -> Result<u16> {
+    bytes
+        .get(pos..pos + 2)
+        .map(|s| u16::from_be_bytes([s[0], s[1]]))
+        .ok_or_else(|| DecompilerError::ParseError("EOF: truncated size field".into()))
+}
+
+/// Clamp `bytes[pos..pos + len]` to the available input.
+fn slice(bytes: &[u8], pos: usize, len: usize) -> &[u8] {
+    let end = (pos + len).min(bytes.len());
+    bytes.get(pos..end).unwrap_or(&[])
+}
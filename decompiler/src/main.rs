@@ -21,12 +21,19 @@
 */
 
 use std::collections::{HashMap, HashSet};
-use clap::Parser;
+use clap::{Parser, Subcommand};
 use serde::{Deserialize, Serialize};
 use thiserror::Error;
 use petgraph::graph::{DiGraph, NodeIndex};
 
+mod bytecode;
+mod cfg;
+mod eof;
+mod resolve;
+mod rpc;
+mod symbolic;
 mod server;
+mod validate;
 
 // â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•
 //                              CLI ARGUMENTS
@@ -38,6 +45,9 @@ mod server;
 #[command(version = "1.0.0")]
 #[command(about = "EVM bytecode decompiler for security analysis")]
 struct Args {
+    /// Target to analyze: a hex bytecode string, a file path, or a 0x… address
+    target: Option<String>,
+
     /// Bytecode hex string (with or without 0x prefix)
     #[arg(short, long)]
     bytecode: Option<String>,
@@ -49,8 +59,20 @@ struct Args {
     /// Chain to query (ethereum, bsc, polygon, etc.)
     #[arg(short, long, default_value = "ethereum")]
     chain: String,
+
+    /// Override the JSON-RPC endpoint with a custom URL
+    #[arg(long)]
+    rpc_url: Option<String>,
+
+    /// Resolve function selectors to textual signatures (requires network)
+    #[arg(long)]
+    resolve: bool,
+
+    /// Offline signature cache file (JSON map of selector -> signatures)
+    #[arg(long)]
+    signature_cache: Option<std::path::PathBuf>,
     
-    /// Output format: json, text, or graph
+    /// Output format: json, text, or graph (alias: dot)
     #[arg(short, long, default_value = "json")]
     output: String,
     
@@ -65,6 +87,26 @@ struct Args {
     /// Port for HTTP server (default: 3000)
     #[arg(short, long, default_value = "3000")]
     port: u16,
+
+    #[command(subcommand)]
+    command: Option<Command>,
+}
+
+#[derive(Subcommand, Debug)]
+enum Command {
+    /// Validate the decompiler against a corpus of fixtures
+    Validate {
+        /// Directory of `*.json` fixtures
+        dir: std::path::PathBuf,
+        /// Fixture file names to skip (repeatable)
+        #[arg(long)]
+        skip: Vec<String>,
+    },
+    /// Emit an expected-instructions fixture for raw bytecode
+    GenFixture {
+        /// Bytecode hex string (with or without 0x prefix)
+        bytecode: String,
+    },
 }
 
 // â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•
@@ -92,169 +134,14 @@ type Result<T> = std::result::Result<T, DecompilerError>;
 //                              EVM OPCODES
 // â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
-#[repr(u8)]
-pub enum Opcode {
-    // Stop & Arithmetic
-    STOP = 0x00,
-    ADD = 0x01,
-    MUL = 0x02,
-    SUB = 0x03,
-    DIV = 0x04,
-    SDIV = 0x05,
-    MOD = 0x06,
-    SMOD = 0x07,
-    ADDMOD = 0x08,
-    MULMOD = 0x09,
-    EXP = 0x0A,
-    SIGNEXTEND = 0x0B,
-    
-    // Comparison & Bitwise
-    LT = 0x10,
-    GT = 0x11,
-    SLT = 0x12,
-    SGT = 0x13,
-    EQ = 0x14,
-    ISZERO = 0x15,
-    AND = 0x16,
-    OR = 0x17,
-    XOR = 0x18,
-    NOT = 0x19,
-    BYTE = 0x1A,
-    SHL = 0x1B,
-    SHR = 0x1C,
-    SAR = 0x1D,
-    
-    // Keccak256
-    SHA3 = 0x20,
-    
-    // Environment
-    ADDRESS = 0x30,
-    BALANCE = 0x31,
-    ORIGIN = 0x32,
-    CALLER = 0x33,
-    CALLVALUE = 0x34,
-    CALLDATALOAD = 0x35,
-    CALLDATASIZE = 0x36,
-    CALLDATACOPY = 0x37,
-    CODESIZE = 0x38,
-    CODECOPY = 0x39,
-    GASPRICE = 0x3A,
-    EXTCODESIZE = 0x3B,
-    EXTCODECOPY = 0x3C,
-    RETURNDATASIZE = 0x3D,
-    RETURNDATACOPY = 0x3E,
-    EXTCODEHASH = 0x3F,
-    
-    // Block info
-    BLOCKHASH = 0x40,
-    COINBASE = 0x41,
-    TIMESTAMP = 0x42,
-    NUMBER = 0x43,
-    DIFFICULTY = 0x44,
-    GASLIMIT = 0x45,
-    CHAINID = 0x46,
-    SELFBALANCE = 0x47,
-    BASEFEE = 0x48,
-    
-    // Stack, Memory, Storage
-    POP = 0x50,
-    MLOAD = 0x51,
-    MSTORE = 0x52,
-    MSTORE8 = 0x53,
-    SLOAD = 0x54,
-    SSTORE = 0x55,
-    JUMP = 0x56,
-    JUMPI = 0x57,
-    PC = 0x58,
-    MSIZE = 0x59,
-    GAS = 0x5A,
-    JUMPDEST = 0x5B,
-    
-    // Push operations (PUSH1 to PUSH32)
-    PUSH1 = 0x60,
-    PUSH2 = 0x61,
-    PUSH3 = 0x62,
-    PUSH4 = 0x63,
-    PUSH32 = 0x7F,
-    
-    // Dup operations
-    DUP1 = 0x80,
-    DUP16 = 0x8F,
-    
-    // Swap operations
-    SWAP1 = 0x90,
-    SWAP16 = 0x9F,
-    
-    // Log operations
-    LOG0 = 0xA0,
-    LOG4 = 0xA4,
-    
-    // System operations
-    CREATE = 0xF0,
-    CALL = 0xF1,
-    CALLCODE = 0xF2,
-    RETURN = 0xF3,
-    DELEGATECALL = 0xF4,
-    CREATE2 = 0xF5,
-    STATICCALL = 0xFA,
-    REVERT = 0xFD,
-    INVALID = 0xFE,
-    SELFDESTRUCT = 0xFF,
-    
-    UNKNOWN = 0xFE,
-}
-
-impl From<u8> for Opcode {
-    fn from(byte: u8) -> Self {
-        match byte {
-            0x00 => Opcode::STOP,
-            0x01 => Opcode::ADD,
-            0x02 => Opcode::MUL,
-            0x03 => Opcode::SUB,
-            0x04 => Opcode::DIV,
-            0x20 => Opcode::SHA3,
-            0x31 => Opcode::BALANCE,
-            0x32 => Opcode::ORIGIN,
-            0x33 => Opcode::CALLER,
-            0x34 => Opcode::CALLVALUE,
-            0x35 => Opcode::CALLDATALOAD,
-            0x54 => Opcode::SLOAD,
-            0x55 => Opcode::SSTORE,
-            0x56 => Opcode::JUMP,
-            0x57 => Opcode::JUMPI,
-            0x5B => Opcode::JUMPDEST,
-            // PUSH operations - return PUSH1 and handle arg size separately
-            0x60..=0x7F => Opcode::PUSH1,  // Safe: all PUSHn map to PUSH1
-            // DUP operations
-            0x80..=0x8F => Opcode::DUP1,   // Safe: all DUPn map to DUP1
-            // SWAP operations 
-            0x90..=0x9F => Opcode::SWAP1,  // Safe: all SWAPn map to SWAP1
-            0xF0 => Opcode::CREATE,
-            0xF1 => Opcode::CALL,
-            0xF2 => Opcode::CALLCODE,
-            0xF3 => Opcode::RETURN,
-            0xF4 => Opcode::DELEGATECALL,
-            0xF5 => Opcode::CREATE2,
-            0xFA => Opcode::STATICCALL,
-            0xFD => Opcode::REVERT,
-            0xFF => Opcode::SELFDESTRUCT,
-            _ => Opcode::UNKNOWN,
-        }
-    }
-}
+// The `Opcode` enum, its `From<u8>` conversion, and the `arg_size`,
+// `stack_inputs`, `stack_outputs`, `base_gas`, and `category` accessors are
+// generated at build time from `instructions.in` (see `build.rs`). Everything
+// that depends on opcode *semantics* beyond the flat table lives in the
+// hand-written prelude below.
+include!(concat!(env!("OUT_DIR"), "/opcodes.rs"));
 
 impl Opcode {
-    /// Returns how many bytes this opcode's argument takes
-    pub fn arg_size(&self) -> usize {
-        let byte = *self as u8;
-        if byte >= 0x60 && byte <= 0x7F {
-            (byte - 0x5F) as usize
-        } else {
-            0
-        }
-    }
-    
     /// Check if this opcode is dangerous for security
     pub fn is_dangerous(&self) -> bool {
         matches!(self, 
@@ -268,6 +155,40 @@ impl Opcode {
         )
     }
     
+    /// Static EVM base gas cost of this opcode.
+    ///
+    /// This is the pre-execution floor used for a quick heaviness estimate, not
+    /// a precise meter: dynamic components (memory expansion, cold/warm access
+    /// refunds, call stipends) are not modelled, so storage and call opcodes
+    /// take their worst-case (cold) tier; BALANCE and the EXTCODE* family keep
+    /// the table's warm cost. The flat-table `base_gas()` covers the
+    /// arithmetic/stack majority; this layer adds the tiered storage/call costs
+    /// that a single table column cannot express.
+    pub fn gas_cost(&self) -> u64 {
+        match self {
+            // Storage (cold-access worst case).
+            Opcode::SLOAD => 2100,
+            Opcode::SSTORE => 20000,
+            Opcode::TLOAD | Opcode::TSTORE => 100,
+            // Call family (base, before value/new-account surcharges).
+            Opcode::CALL | Opcode::CALLCODE => 2600,
+            Opcode::DELEGATECALL | Opcode::STATICCALL => 2600,
+            // Contract creation and destruction.
+            Opcode::CREATE => 32000,
+            Opcode::CREATE2 => 32000,
+            Opcode::SELFDESTRUCT => 5000,
+            // Hashing and logging scale with data but have a fixed floor.
+            Opcode::SHA3 => 30,
+            Opcode::LOG0 => 375,
+            Opcode::LOG1 => 750,
+            Opcode::LOG2 => 1125,
+            Opcode::LOG3 => 1500,
+            Opcode::LOG4 => 1875,
+            // Everything else falls back to the generated flat-table cost.
+            _ => self.base_gas(),
+        }
+    }
+
     /// Check if this is a control flow opcode
     pub fn is_control_flow(&self) -> bool {
         matches!(self,
@@ -376,10 +297,48 @@ pub struct BasicBlock {
     pub is_return: bool,
 }
 
+/// How control passes from one basic block to the next.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EdgeKind {
+    /// Straight-line fall-through into the following block.
+    Fallthrough,
+    /// Unconditional `JUMP` to a resolved target.
+    Jump,
+    /// `JUMPI` taken edge.
+    ConditionalTrue,
+    /// `JUMPI` not-taken (fall-through) edge.
+    ConditionalFalse,
+}
+
+impl EdgeKind {
+    /// Short label used in Graphviz output.
+    pub fn label(&self) -> &'static str {
+        match self {
+            EdgeKind::Fallthrough => "fallthrough",
+            EdgeKind::Jump => "jump",
+            EdgeKind::ConditionalTrue => "true",
+            EdgeKind::ConditionalFalse => "false",
+        }
+    }
+}
+
 pub struct ControlFlowGraph {
-    pub graph: DiGraph<BasicBlock, ()>,
+    pub graph: DiGraph<BasicBlock, EdgeKind>,
     pub entry: Option<NodeIndex>,
     pub blocks: HashMap<usize, NodeIndex>,
+    /// Offsets of blocks ending in a `JUMP`/`JUMPI` whose target could not be
+    /// resolved to a constant (i.e. it is computed at runtime). Dynamic dispatch
+    /// is itself a security signal, so we keep it rather than silently dropping
+    /// the edge.
+    pub unresolved_jumps: Vec<usize>,
+}
+
+/// A single slot on the abstract stack used to resolve jump targets: either a
+/// known constant (a literal pushed by `PUSHn`) or an opaque runtime value.
+#[derive(Debug, Clone, Copy)]
+enum StackSlot {
+    Const(u64),
+    Unknown,
 }
 
 impl ControlFlowGraph {
@@ -388,6 +347,7 @@ impl ControlFlowGraph {
             graph: DiGraph::new(),
             entry: None,
             blocks: HashMap::new(),
+            unresolved_jumps: Vec::new(),
         };
         
         // Find all basic block leaders (JUMPDEST, after JUMP/JUMPI/STOP/etc)
@@ -443,13 +403,389 @@ impl ControlFlowGraph {
                 cfg.entry = Some(node);
             }
         }
-        
+
+        cfg.build_edges();
+        cfg.resolve_dynamic_jumps();
         cfg
     }
-    
+
+    /// Resolve control-flow edges between the already-created basic blocks.
+    ///
+    /// Each block is walked with a small symbolic stack that tracks constant
+    /// values (`PUSHn` literals propagated through `DUPn`/`SWAPn`). When a block
+    /// ends in `JUMP`/`JUMPI` and the stack-top is a constant pointing at a
+    /// valid `JUMPDEST`, a concrete edge is added; otherwise the jump is
+    /// recorded as unresolved. Ordinary fall-through blocks get an edge to the
+    /// following block.
+    fn build_edges(&mut self) {
+        // Valid jump destinations, keyed by byte offset.
+        let jumpdests: HashSet<usize> = self
+            .graph
+            .node_weights()
+            .flat_map(|b| b.instructions.iter())
+            .filter(|i| i.opcode == Opcode::JUMPDEST)
+            .map(|i| i.offset)
+            .collect();
+
+        // Block start offsets in ascending order, so we can find "the next block".
+        let mut starts: Vec<usize> = self.blocks.keys().copied().collect();
+        starts.sort_unstable();
+
+        let mut edges: Vec<(usize, usize, EdgeKind)> = Vec::new();
+        let mut unresolved: Vec<usize> = Vec::new();
+
+        for (pos, &start) in starts.iter().enumerate() {
+            let node = self.blocks[&start];
+            let block = &self.graph[node];
+            let next_start = starts.get(pos + 1).copied();
+            let last = match block.instructions.last() {
+                Some(i) => i,
+                None => continue,
+            };
+
+            let top = Self::simulate_block(&block.instructions);
+
+            match last.opcode {
+                Opcode::JUMP => match top {
+                    Some(StackSlot::Const(target)) if jumpdests.contains(&(target as usize)) => {
+                        edges.push((start, target as usize, EdgeKind::Jump));
+                    }
+                    _ => unresolved.push(start),
+                },
+                Opcode::JUMPI => {
+                    match top {
+                        Some(StackSlot::Const(target))
+                            if jumpdests.contains(&(target as usize)) =>
+                        {
+                            edges.push((start, target as usize, EdgeKind::ConditionalTrue));
+                        }
+                        _ => unresolved.push(start),
+                    }
+                    // Conditional jumps always have a fall-through edge too.
+                    if let Some(next) = next_start {
+                        edges.push((start, next, EdgeKind::ConditionalFalse));
+                    }
+                }
+                // Terminators with no successor.
+                Opcode::STOP
+                | Opcode::RETURN
+                | Opcode::REVERT
+                | Opcode::INVALID
+                | Opcode::SELFDESTRUCT => {}
+                // Everything else falls through to the next block.
+                _ => {
+                    if let Some(next) = next_start {
+                        edges.push((start, next, EdgeKind::Fallthrough));
+                    }
+                }
+            }
+        }
+
+        for (from, to, kind) in edges {
+            if let (Some(&a), Some(&b)) = (self.blocks.get(&from), self.blocks.get(&to)) {
+                self.graph.add_edge(a, b, kind);
+            }
+        }
+        self.unresolved_jumps = unresolved;
+    }
+
+    /// Refine the CFG by propagating symbolic stacks across edges until a fixed
+    /// point, so jumps whose target is computed in a predecessor block (via
+    /// `DUP`/`SWAP`/arithmetic rather than an immediately-preceding `PUSH`) can
+    /// still be resolved. Incoming stacks are merged by demoting disagreeing
+    /// slots to `Unknown`, and the iteration count is capped to guard against
+    /// loops.
+    fn resolve_dynamic_jumps(&mut self) {
+        const MAX_ITERS: usize = 256;
+
+        let jumpdests: HashSet<usize> = self
+            .graph
+            .node_weights()
+            .flat_map(|b| b.instructions.iter())
+            .filter(|i| i.opcode == Opcode::JUMPDEST)
+            .map(|i| i.offset)
+            .collect();
+
+        let mut starts: Vec<usize> = self.blocks.keys().copied().collect();
+        starts.sort_unstable();
+
+        // Entry stack for each block; the entry block starts empty.
+        let mut entry: HashMap<usize, Vec<StackSlot>> = HashMap::new();
+        if let Some(e) = self.entry {
+            entry.insert(self.graph[e].start_offset, Vec::new());
+        }
+
+        let mut new_edges: HashSet<(usize, usize)> = HashSet::new();
+        let mut iters = 0;
+        let mut changed = true;
+
+        while changed && iters < MAX_ITERS {
+            changed = false;
+            iters += 1;
+
+            for &start in &starts {
+                let in_stack = match entry.get(&start) {
+                    Some(s) => s.clone(),
+                    None => continue, // not yet reached
+                };
+                let node = self.blocks[&start];
+                let (exit, target) =
+                    Self::simulate_full(&self.graph[node].instructions, in_stack);
+
+                // A previously-unresolved jump may now resolve against the
+                // propagated entry stack. Skip targets `build_edges` already
+                // linked — `add_edge` does not deduplicate, so re-adding would
+                // leave a second parallel edge and double the successor.
+                if let Some(StackSlot::Const(t)) = target {
+                    let t = t as usize;
+                    if jumpdests.contains(&t) && new_edges.insert((start, t)) {
+                        if let (Some(&a), Some(&b)) =
+                            (self.blocks.get(&start), self.blocks.get(&t))
+                        {
+                            if self.graph.find_edge(a, b).is_none() {
+                                let kind = match self.graph[a].instructions.last().map(|i| i.opcode) {
+                                    Some(Opcode::JUMPI) => EdgeKind::ConditionalTrue,
+                                    _ => EdgeKind::Jump,
+                                };
+                                self.graph.add_edge(a, b, kind);
+                                changed = true;
+                            }
+                        }
+                    }
+                }
+
+                // Propagate the exit stack to successors.
+                let succs = self.successors(start);
+                for succ in succs {
+                    let merged = match entry.get(&succ) {
+                        Some(existing) => Self::merge_stacks(existing, &exit),
+                        None => exit.clone(),
+                    };
+                    if entry.get(&succ).map(|e| e != &merged).unwrap_or(true) {
+                        entry.insert(succ, merged);
+                        changed = true;
+                    }
+                }
+            }
+        }
+
+        // Recompute the unresolved set: a block ending in a jump whose target
+        // edge is missing. A `JUMPI` always carries a `ConditionalFalse`
+        // fall-through edge, so "no successors" never holds for it; we must look
+        // specifically for the absence of a taken edge (`Jump`/`ConditionalTrue`).
+        let mut unresolved = Vec::new();
+        for &start in &starts {
+            let node = self.blocks[&start];
+            let ends_in_jump = matches!(
+                self.graph[node].instructions.last().map(|i| i.opcode),
+                Some(Opcode::JUMP) | Some(Opcode::JUMPI)
+            );
+            if !ends_in_jump {
+                continue;
+            }
+            let has_taken_edge = self.graph.edge_indices().any(|edge| {
+                self.graph.edge_endpoints(edge).map(|(a, _)| a) == Some(node)
+                    && matches!(self.graph[edge], EdgeKind::Jump | EdgeKind::ConditionalTrue)
+            });
+            if !has_taken_edge {
+                unresolved.push(start);
+            }
+        }
+        self.unresolved_jumps = unresolved;
+    }
+
+    /// Merge two symbolic stacks, aligning from the top; slots that disagree (or
+    /// are missing in one side) become `Unknown`.
+    fn merge_stacks(a: &[StackSlot], b: &[StackSlot]) -> Vec<StackSlot> {
+        let depth = a.len().min(b.len());
+        let mut merged = Vec::with_capacity(depth);
+        for i in 0..depth {
+            let sa = a[a.len() - depth + i];
+            let sb = b[b.len() - depth + i];
+            merged.push(match (sa, sb) {
+                (StackSlot::Const(x), StackSlot::Const(y)) if x == y => StackSlot::Const(x),
+                _ => StackSlot::Unknown,
+            });
+        }
+        merged
+    }
+
+    /// Interpret a block starting from `entry`, returning the full exit stack and
+    /// the value a trailing `JUMP`/`JUMPI` consumes as its target (if any).
+    fn simulate_full(
+        instructions: &[Instruction],
+        entry: Vec<StackSlot>,
+    ) -> (Vec<StackSlot>, Option<StackSlot>) {
+        let mut stack = entry;
+        let mut target = None;
+
+        for (idx, instr) in instructions.iter().enumerate() {
+            let is_last = idx + 1 == instructions.len();
+            if is_last && matches!(instr.opcode, Opcode::JUMP | Opcode::JUMPI) {
+                target = stack.last().copied();
+            }
+            match instr.opcode {
+                op if op.category() == "push" => stack.push(push_value(instr)),
+                op if op.category() == "dup" => {
+                    let n = (instr.raw_byte - 0x80 + 1) as usize;
+                    let slot = stack
+                        .len()
+                        .checked_sub(n)
+                        .map(|i| stack[i])
+                        .unwrap_or(StackSlot::Unknown);
+                    stack.push(slot);
+                }
+                op if op.category() == "swap" => {
+                    let n = (instr.raw_byte - 0x90 + 1) as usize;
+                    let len = stack.len();
+                    if len > n {
+                        stack.swap(len - 1, len - 1 - n);
+                    }
+                }
+                op => {
+                    for _ in 0..op.stack_inputs() {
+                        stack.pop();
+                    }
+                    for _ in 0..op.stack_outputs() {
+                        stack.push(StackSlot::Unknown);
+                    }
+                }
+            }
+        }
+
+        (stack, target)
+    }
+
+    /// Run the symbolic stack over a block and return the value that a trailing
+    /// `JUMP`/`JUMPI` would consume as its target (the stack-top before the
+    /// jump itself executes). Returns `None` if the block does not end in a jump
+    /// or the stack underflows.
+    fn simulate_block(instructions: &[Instruction]) -> Option<StackSlot> {
+        let mut stack: Vec<StackSlot> = Vec::new();
+
+        for (idx, instr) in instructions.iter().enumerate() {
+            let is_last = idx + 1 == instructions.len();
+            match instr.opcode {
+                Opcode::JUMP | Opcode::JUMPI if is_last => {
+                    return stack.last().copied();
+                }
+                op if op.category() == "push" => {
+                    stack.push(push_value(instr));
+                }
+                op if op.category() == "dup" => {
+                    // DUPn duplicates the n-th item from the top.
+                    let n = (instr.raw_byte - 0x80 + 1) as usize;
+                    let slot = stack
+                        .len()
+                        .checked_sub(n)
+                        .map(|i| stack[i])
+                        .unwrap_or(StackSlot::Unknown);
+                    stack.push(slot);
+                }
+                op if op.category() == "swap" => {
+                    // SWAPn swaps the top with the (n+1)-th item.
+                    let n = (instr.raw_byte - 0x90 + 1) as usize;
+                    let len = stack.len();
+                    if len > n {
+                        stack.swap(len - 1, len - 1 - n);
+                    }
+                }
+                op => {
+                    for _ in 0..op.stack_inputs() {
+                        stack.pop();
+                    }
+                    for _ in 0..op.stack_outputs() {
+                        stack.push(StackSlot::Unknown);
+                    }
+                }
+            }
+        }
+        None
+    }
+
     pub fn block_count(&self) -> usize {
         self.graph.node_count()
     }
+
+    /// Render the graph as a Graphviz `digraph`. Each node is labelled with its
+    /// start offset and the opcode mnemonics in the block; edges carry their
+    /// `EdgeKind`. When `color_dangerous` is set, blocks containing a dangerous
+    /// opcode (CALL/DELEGATECALL/SELFDESTRUCT, etc.) are filled red.
+    pub fn to_dot(&self, color_dangerous: bool) -> String {
+        let mut out = String::from("digraph cfg {\n");
+        out.push_str("    node [shape=box];\n");
+
+        for node in self.graph.node_indices() {
+            let block = &self.graph[node];
+            let mnemonics: Vec<String> = block
+                .instructions
+                .iter()
+                .map(|i| format!("{:?}", i.opcode))
+                .collect();
+            let label = format!("0x{:x}: {}", block.start_offset, mnemonics.join(" "));
+            let color = if color_dangerous
+                && block.instructions.iter().any(|i| i.opcode.is_dangerous())
+            {
+                ", style=filled, fillcolor=\"#ff6666\""
+            } else {
+                ""
+            };
+            out.push_str(&format!(
+                "    n{} [label=\"{}\"{}];\n",
+                block.start_offset,
+                escape_dot(&label),
+                color
+            ));
+        }
+
+        for edge in self.graph.edge_indices() {
+            if let Some((a, b)) = self.graph.edge_endpoints(edge) {
+                let kind = self.graph[edge];
+                out.push_str(&format!(
+                    "    n{} -> n{} [label=\"{}\"];\n",
+                    self.graph[a].start_offset,
+                    self.graph[b].start_offset,
+                    kind.label()
+                ));
+            }
+        }
+
+        out.push_str("}\n");
+        out
+    }
+
+    /// Byte offsets of the basic blocks reachable in one step from the block
+    /// starting at `offset`.
+    pub fn successors(&self, offset: usize) -> Vec<usize> {
+        let node = match self.blocks.get(&offset) {
+            Some(n) => *n,
+            None => return Vec::new(),
+        };
+        self.graph
+            .neighbors(node)
+            .map(|n| self.graph[n].start_offset)
+            .collect()
+    }
+}
+
+/// Escape a string for use inside a Graphviz double-quoted label.
+fn escape_dot(s: &str) -> String {
+    s.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+/// Decode a `PUSHn` immediate into a concrete stack slot, or `Unknown` when the
+/// literal is wider than a `u64` (too large to be a jump target anyway).
+fn push_value(instr: &Instruction) -> StackSlot {
+    match &instr.argument {
+        Some(bytes) if bytes.len() <= 8 => {
+            let mut arr = [0u8; 8];
+            arr[8 - bytes.len()..].copy_from_slice(bytes);
+            StackSlot::Const(u64::from_be_bytes(arr))
+        }
+        // PUSH0 has no argument and pushes a literal zero.
+        None if instr.opcode == Opcode::PUSH0 => StackSlot::Const(0),
+        _ => StackSlot::Unknown,
+    }
 }
 
 // â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•
@@ -459,13 +795,22 @@ impl ControlFlowGraph {
 #[derive(Debug, Serialize, Deserialize)]
 pub struct SecurityAnalysis {
     pub function_selectors: Vec<String>,
+    /// topic0 hashes recovered from LOG1..LOG4 opcodes (constant topics only).
+    #[serde(default)]
+    pub event_signatures: Vec<String>,
     pub dangerous_opcodes: Vec<DangerousOpcode>,
     pub external_calls: usize,
     pub storage_writes: usize,
     pub has_selfdestruct: bool,
     pub has_delegatecall: bool,
+    #[serde(default)]
+    pub has_callcode: bool,
     pub has_create: bool,
     pub complexity_score: u32,
+    /// Static gas floor summed over the disassembly (see [`Opcode::gas_cost`]).
+    /// A quick proxy for how heavy the contract is; dynamic costs are excluded.
+    #[serde(default)]
+    pub estimated_gas: u64,
     pub risk_indicators: Vec<RiskIndicator>,
 }
 
@@ -481,6 +826,10 @@ pub struct RiskIndicator {
     pub name: String,
     pub severity: String, // "critical", "high", "medium", "low"
     pub description: String,
+    /// Coarse grouping, e.g. "self-destruct", "delegatecall", "reentrancy",
+    /// "proxy".
+    #[serde(default)]
+    pub category: String,
 }
 
 pub struct SecurityAnalyzer;
@@ -493,8 +842,13 @@ impl SecurityAnalyzer {
         let mut storage_writes = 0;
         let mut has_selfdestruct = false;
         let mut has_delegatecall = false;
+        let mut has_callcode = false;
         let mut has_create = false;
         let mut risks = Vec::new();
+
+        // Operand provenance for dangerous opcodes, so risk severity can reflect
+        // where an address/beneficiary came from.
+        let provenance = symbolic::operand_provenance(instructions);
         
         // Look for function selectors (PUSH4 followed by EQ)
         for window in instructions.windows(2) {
@@ -514,6 +868,9 @@ impl SecurityAnalyzer {
             match instr.opcode {
                 Opcode::CALL | Opcode::CALLCODE | Opcode::STATICCALL => {
                     external_calls += 1;
+                    if instr.opcode == Opcode::CALLCODE {
+                        has_callcode = true;
+                    }
                     dangerous.push(DangerousOpcode {
                         offset: instr.offset,
                         opcode: format!("{:?}", instr.opcode),
@@ -523,11 +880,28 @@ impl SecurityAnalyzer {
                 Opcode::DELEGATECALL => {
                     has_delegatecall = true;
                     external_calls += 1;
+                    let target = provenance.get(&instr.offset);
                     dangerous.push(DangerousOpcode {
                         offset: instr.offset,
                         opcode: "DELEGATECALL".to_string(),
                         risk: "Delegatecall - storage manipulation risk".to_string(),
                     });
+                    // A delegatecall to a caller/calldata-controlled address is
+                    // far more dangerous than one to a fixed implementation.
+                    if let Some(p) = target {
+                        if p.is_caller_derived() || matches!(p, symbolic::Provenance::CallData(_)) {
+                            risks.push(RiskIndicator {
+                                name: "Attacker-controlled delegatecall".to_string(),
+                                severity: "critical".to_string(),
+                                description: format!(
+                                    "delegatecall at 0x{:x} targets an address derived from {}",
+                                    instr.offset,
+                                    p.describe()
+                                ),
+                                category: "delegatecall".to_string(),
+                            });
+                        }
+                    }
                 }
                 Opcode::SSTORE => {
                     storage_writes += 1;
@@ -539,10 +913,28 @@ impl SecurityAnalyzer {
                         opcode: "SELFDESTRUCT".to_string(),
                         risk: "Contract can be destroyed".to_string(),
                     });
+                    // Severity depends on who the beneficiary is: a fixed
+                    // constant is a weaker signal than a caller-derived address.
+                    let beneficiary = provenance.get(&instr.offset);
+                    let (severity, description) = match beneficiary {
+                        Some(p) if p.is_caller_derived() => (
+                            "critical",
+                            format!("beneficiary is caller-derived ({})", p.describe()),
+                        ),
+                        Some(symbolic::Provenance::Constant(_)) => (
+                            "high",
+                            "beneficiary is a constant address".to_string(),
+                        ),
+                        _ => (
+                            "critical",
+                            "Contract can be destroyed, all funds sent to owner".to_string(),
+                        ),
+                    };
                     risks.push(RiskIndicator {
                         name: "Self-destruct capability".to_string(),
-                        severity: "critical".to_string(),
-                        description: "Contract can be destroyed, all funds sent to owner".to_string(),
+                        severity: severity.to_string(),
+                        description,
+                        category: "self-destruct".to_string(),
                     });
                 }
                 Opcode::CREATE | Opcode::CREATE2 => {
@@ -557,11 +949,18 @@ impl SecurityAnalyzer {
             }
         }
         
-        // Calculate complexity
+        // Static gas floor over the whole disassembly.
+        let estimated_gas: u64 = instructions.iter().map(|i| i.opcode.gas_cost()).sum();
+
+        // Calculate complexity. The structural terms are retained, then
+        // gas-weighted so that a contract dominated by storage writes or calls
+        // (gas-griefing-prone shapes) scores above one of the same block count
+        // made of cheap arithmetic.
         let cfg = ControlFlowGraph::build(instructions);
-        let complexity = (cfg.block_count() as u32 * 10) 
+        let complexity = (cfg.block_count() as u32 * 10)
             + (external_calls as u32 * 20)
-            + (storage_writes as u32 * 5);
+            + (storage_writes as u32 * 5)
+            + (estimated_gas / 1000) as u32;
         
         // Add risk indicators based on patterns
         if has_delegatecall {
@@ -569,6 +968,7 @@ impl SecurityAnalyzer {
                 name: "Delegatecall usage".to_string(),
                 severity: "high".to_string(),
                 description: "Contract uses delegatecall - verify upgrade mechanism".to_string(),
+                category: "delegatecall".to_string(),
             });
         }
         
@@ -577,21 +977,255 @@ impl SecurityAnalyzer {
                 name: "Multiple external calls".to_string(),
                 severity: "medium".to_string(),
                 description: format!("{} external calls - check for reentrancy", external_calls),
+                category: "reentrancy".to_string(),
             });
         }
-        
+
+        // Ordering-aware reentrancy detection over the CFG.
+        risks.extend(Self::detect_reentrancy(instructions, &cfg));
+
+        // Proxy / delegatecall dispatch classification.
+        risks.extend(Self::detect_proxy(instructions, &provenance));
+
         SecurityAnalysis {
             function_selectors: selectors,
+            event_signatures: Self::extract_event_topics(instructions),
             dangerous_opcodes: dangerous,
             external_calls,
             storage_writes,
             has_selfdestruct,
             has_delegatecall,
+            has_callcode,
             has_create,
             complexity_score: complexity,
+            estimated_gas,
             risk_indicators: risks,
         }
     }
+
+    /// Recover constant topic0 hashes from `LOG1..LOG4` opcodes. A small
+    /// constant stack is walked (reset at `JUMPDEST` merge points); at each
+    /// `LOGn` the topic0 slot (below the memory offset/length pair) is read and,
+    /// when it is a known constant, recorded as a 32-byte hash. Results are
+    /// de-duplicated while preserving first-seen order.
+    fn extract_event_topics(instructions: &[Instruction]) -> Vec<String> {
+        let mut stack: Vec<Option<Vec<u8>>> = Vec::new();
+        let mut topics: Vec<String> = Vec::new();
+
+        let nth = |stack: &[Option<Vec<u8>>], n: usize| -> Option<Vec<u8>> {
+            stack.len().checked_sub(n + 1).and_then(|i| stack[i].clone())
+        };
+
+        for instr in instructions {
+            let op = instr.opcode;
+            match op {
+                Opcode::JUMPDEST => stack.clear(),
+                Opcode::LOG1 | Opcode::LOG2 | Opcode::LOG3 | Opcode::LOG4 => {
+                    // LOGn: [offset, length, topic0, ...]; topic0 is 3rd from top.
+                    if let Some(bytes) = nth(&stack, 2) {
+                        let mut arr = [0u8; 32];
+                        let take = bytes.len().min(32);
+                        arr[32 - take..].copy_from_slice(&bytes[bytes.len() - take..]);
+                        let hash = format!("0x{}", hex::encode(arr));
+                        if !topics.contains(&hash) {
+                            topics.push(hash);
+                        }
+                    }
+                }
+                _ => {}
+            }
+
+            // Advance the constant stack.
+            match op {
+                _ if op.category() == "push" => {
+                    stack.push(instr.argument.clone());
+                }
+                _ if op.category() == "dup" => {
+                    let n = (instr.raw_byte - 0x80 + 1) as usize;
+                    stack.push(nth(&stack, n - 1));
+                }
+                _ if op.category() == "swap" => {
+                    let n = (instr.raw_byte - 0x90 + 1) as usize;
+                    let len = stack.len();
+                    if len > n {
+                        stack.swap(len - 1, len - 1 - n);
+                    }
+                }
+                Opcode::JUMPDEST => {}
+                _ => {
+                    for _ in 0..op.stack_inputs() {
+                        stack.pop();
+                    }
+                    for _ in 0..op.stack_outputs() {
+                        stack.push(None);
+                    }
+                }
+            }
+        }
+
+        topics
+    }
+
+    /// Classify proxy / delegatecall dispatch idioms. Recognises EIP-1167
+    /// minimal proxies (by their well-known runtime signature), delegatecalls
+    /// to a storage-loaded implementation slot (transparent/UUPS proxies), and
+    /// delegatecalls to a calldata-derived address. Where the implementation is
+    /// a constant embedded in the bytecode, the address is named.
+    fn detect_proxy(
+        instructions: &[Instruction],
+        provenance: &HashMap<usize, symbolic::Provenance>,
+    ) -> Vec<RiskIndicator> {
+        let mut risks = Vec::new();
+
+        // Reassemble the raw byte stream to match the EIP-1167 signature.
+        let mut bytes = Vec::new();
+        for instr in instructions {
+            bytes.push(instr.raw_byte);
+            if let Some(arg) = &instr.argument {
+                bytes.extend_from_slice(arg);
+            }
+        }
+        // 363d3d373d3d3d363d73 <20-byte impl> 5af4...
+        const PREFIX: [u8; 10] = [0x36, 0x3d, 0x3d, 0x37, 0x3d, 0x3d, 0x3d, 0x36, 0x3d, 0x73];
+        if let Some(pos) = bytes.windows(PREFIX.len()).position(|w| w == PREFIX) {
+            let addr_start = pos + PREFIX.len();
+            if addr_start + 20 <= bytes.len() {
+                let addr = hex::encode(&bytes[addr_start..addr_start + 20]);
+                risks.push(RiskIndicator {
+                    name: "minimal-proxy".to_string(),
+                    severity: "medium".to_string(),
+                    description: format!("EIP-1167 minimal proxy forwarding all calls to 0x{}", addr),
+                    category: "proxy".to_string(),
+                });
+            }
+        }
+
+        // Classify each delegatecall by the provenance of its target address.
+        for (i, instr) in instructions.iter().enumerate() {
+            if instr.opcode != Opcode::DELEGATECALL {
+                continue;
+            }
+            match provenance.get(&instr.offset) {
+                Some(symbolic::Provenance::Constant(addr)) => {
+                    risks.push(RiskIndicator {
+                        name: "delegatecall-to-constant".to_string(),
+                        severity: "medium".to_string(),
+                        description: format!(
+                            "delegatecall at 0x{:x} forwards to constant 0x{}",
+                            instr.offset,
+                            hex::encode(addr)
+                        ),
+                        category: "proxy".to_string(),
+                    });
+                }
+                Some(symbolic::Provenance::CallData(_)) => {
+                    risks.push(RiskIndicator {
+                        name: "delegatecall-to-calldata".to_string(),
+                        severity: "high".to_string(),
+                        description: format!(
+                            "delegatecall at 0x{:x} forwards to a calldata-derived address",
+                            instr.offset
+                        ),
+                        category: "proxy".to_string(),
+                    });
+                }
+                // An implementation loaded from a storage slot is the classic
+                // transparent/UUPS pattern; approximate it by a preceding SLOAD.
+                _ if instructions[..i].iter().rev().take(8).any(|p| p.opcode == Opcode::SLOAD) => {
+                    risks.push(RiskIndicator {
+                        name: "delegatecall-to-storage-slot".to_string(),
+                        severity: "medium".to_string(),
+                        description: format!(
+                            "delegatecall at 0x{:x} forwards to a storage-loaded implementation slot",
+                            instr.offset
+                        ),
+                        category: "proxy".to_string(),
+                    });
+                }
+                _ => {}
+            }
+        }
+
+        risks
+    }
+
+    /// Flag checks-effects-interactions violations: an `SSTORE` that is
+    /// reachable *after* an external call along some control-flow path. When
+    /// the contract implements a transient-storage reentrancy guard
+    /// (`TSTORE`/`TLOAD`), the finding is downgraded since the state write is
+    /// protected against re-entry.
+    fn detect_reentrancy(instructions: &[Instruction], cfg: &ControlFlowGraph) -> Vec<RiskIndicator> {
+        let mut risks = Vec::new();
+
+        // A transient-storage guard anywhere in the code mitigates reentrancy.
+        let guarded = instructions.iter().any(|i| i.opcode == Opcode::TSTORE)
+            && instructions.iter().any(|i| i.opcode == Opcode::TLOAD);
+
+        for call in instructions.iter().filter(|i| {
+            matches!(i.opcode, Opcode::CALL | Opcode::DELEGATECALL | Opcode::CALLCODE)
+        }) {
+            if let Some(sstore_offset) = Self::sstore_reachable_after(call.offset, cfg) {
+                let severity = if guarded { "low" } else { "high" };
+                let guard_note = if guarded {
+                    " (transient-storage guard detected)"
+                } else {
+                    ""
+                };
+                risks.push(RiskIndicator {
+                    name: "Reentrancy (checks-effects-interactions)".to_string(),
+                    severity: severity.to_string(),
+                    description: format!(
+                        "external call at 0x{:x} is followed by SSTORE at 0x{:x}{}",
+                        call.offset, sstore_offset, guard_note
+                    ),
+                    category: "reentrancy".to_string(),
+                });
+            }
+        }
+
+        risks
+    }
+
+    /// Return the offset of the first `SSTORE` reachable after the external call
+    /// at `call_offset`: later in the same block, or in any CFG successor block.
+    fn sstore_reachable_after(call_offset: usize, cfg: &ControlFlowGraph) -> Option<usize> {
+        // Locate the block containing the call.
+        let call_block = cfg
+            .graph
+            .node_weights()
+            .find(|b| b.instructions.iter().any(|i| i.offset == call_offset))?;
+
+        // SSTORE later in the call's own block.
+        if let Some(sstore) = call_block
+            .instructions
+            .iter()
+            .find(|i| i.offset > call_offset && i.opcode == Opcode::SSTORE)
+        {
+            return Some(sstore.offset);
+        }
+
+        // Walk successor blocks depth-first.
+        let mut visited: HashSet<usize> = HashSet::new();
+        visited.insert(call_block.start_offset);
+        let mut queue: Vec<usize> = cfg.successors(call_block.start_offset);
+        while let Some(start) = queue.pop() {
+            if !visited.insert(start) {
+                continue;
+            }
+            if let Some(&node) = cfg.blocks.get(&start) {
+                if let Some(sstore) = cfg.graph[node]
+                    .instructions
+                    .iter()
+                    .find(|i| i.opcode == Opcode::SSTORE)
+                {
+                    return Some(sstore.offset);
+                }
+            }
+            queue.extend(cfg.successors(start));
+        }
+
+        None
+    }
 }
 
 // â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•â•
@@ -604,6 +1238,23 @@ pub struct DecompilerOutput {
     pub bytecode_size: usize,
     pub instruction_count: usize,
     pub block_count: usize,
+    /// Detected container format: `"legacy"` or `"eof"`.
+    pub container_format: String,
+    /// Structural validation errors (EOF containers only; empty otherwise).
+    pub validation_errors: Vec<String>,
+    /// Selector -> candidate textual signatures, populated when resolution is
+    /// enabled; empty for offline/air-gapped runs.
+    #[serde(default)]
+    pub resolved_signatures: HashMap<String, Vec<String>>,
+    /// Event topic0 hash -> candidate event signatures (same opt-in as above).
+    #[serde(default)]
+    pub resolved_events: HashMap<String, Vec<String>>,
+    /// Serializable control-flow graph (nodes, successors, entry).
+    #[serde(default)]
+    pub cfg: cfg::CfgView,
+    /// Minimal per-function pseudocode snapshots grouped by selector.
+    #[serde(default)]
+    pub pseudocode: Vec<cfg::FunctionSnapshot>,
     pub security: SecurityAnalysis,
 }
 
@@ -616,21 +1267,54 @@ impl Decompiler {
         let mut hasher = Keccak256::new();
         hasher.update(bytecode);
         let hash = format!("0x{}", hex::encode(hasher.finalize()));
-        
-        // Disassemble
-        let instructions = Disassembler::disassemble(bytecode)?;
-        
+
+        // Dispatch on container format: legacy bytecode is disassembled as a
+        // single stream, whereas an EOF container is split into code sections
+        // that are disassembled independently.
+        let (container_format, validation_errors, instructions) = match eof::Bytecode::parse(bytecode)? {
+            eof::Bytecode::Legacy(code) => {
+                ("legacy".to_string(), Vec::new(), Disassembler::disassemble(&code)?)
+            }
+            eof::Bytecode::Eof(container) => {
+                // Each section is disassembled independently with offsets
+                // restarting at 0, so rebase every section into a unique
+                // contiguous range before concatenating. Otherwise sections
+                // share offsets and the CFG's `blocks` map (keyed by offset)
+                // and selector/event analysis clobber each other across
+                // sections.
+                let mut instructions = Vec::new();
+                let mut base = 0usize;
+                for section in &container.code_sections {
+                    let mut section_instructions = Disassembler::disassemble(section)?;
+                    for instr in &mut section_instructions {
+                        instr.offset += base;
+                    }
+                    base += section.len();
+                    instructions.extend(section_instructions);
+                }
+                ("eof".to_string(), container.validation_errors, instructions)
+            }
+        };
+
         // Build CFG
-        let cfg = ControlFlowGraph::build(&instructions);
-        
+        let control_flow = ControlFlowGraph::build(&instructions);
+        let cfg_view = cfg::CfgView::from_graph(&control_flow);
+        let pseudocode = cfg::function_snapshots(&instructions, &control_flow);
+
         // Security analysis
         let security = SecurityAnalyzer::analyze(&instructions);
-        
+
         Ok(DecompilerOutput {
             bytecode_hash: hash,
             bytecode_size: bytecode.len(),
             instruction_count: instructions.len(),
-            block_count: cfg.block_count(),
+            block_count: control_flow.block_count(),
+            container_format,
+            validation_errors,
+            resolved_signatures: HashMap::new(),
+            resolved_events: HashMap::new(),
+            cfg: cfg_view,
+            pseudocode,
             security,
         })
     }
@@ -651,6 +1335,30 @@ async fn main() -> anyhow::Result<()> {
             .init();
     }
     
+    // Sub-commands (validation harness / fixture generation)
+    match &args.command {
+        Some(Command::Validate { dir, skip }) => {
+            let report = validate::run(dir, skip)?;
+            println!(
+                "validation: {} passed, {} failed, {} skipped",
+                report.passed, report.failed, report.skipped
+            );
+            for m in &report.mismatches {
+                println!("  MISMATCH {}", m);
+            }
+            std::process::exit(if report.ok() { 0 } else { 1 });
+        }
+        Some(Command::GenFixture { bytecode }) => {
+            let clean = bytecode.strip_prefix("0x").unwrap_or(bytecode);
+            let bytes =
+                hex::decode(clean).map_err(|e| DecompilerError::InvalidBytecode(e.to_string()))?;
+            let fixture = validate::Fixture::generate(&bytes)?;
+            println!("{}", serde_json::to_string_pretty(&fixture)?);
+            return Ok(());
+        }
+        None => {}
+    }
+
     // Server mode
     if args.server {
         server::run_server(args.port).await?;
@@ -674,13 +1382,15 @@ async fn main() -> anyhow::Result<()> {
 "#);
     
     // Get bytecode
-    let bytecode = if let Some(hex_str) = &args.bytecode {
+    let bytecode = if let Some(target) = &args.target {
+        bytecode::get_bytecode_from_target(target, &args.chain, args.rpc_url.as_deref()).await?
+    } else if let Some(hex_str) = &args.bytecode {
         let clean = hex_str.strip_prefix("0x").unwrap_or(hex_str);
         hex::decode(clean).map_err(|e| DecompilerError::InvalidBytecode(e.to_string()))?
-    } else if let Some(_address) = &args.address {
-        // TODO: Fetch from RPC
-        eprintln!("âš ï¸  Address fetching not yet implemented");
-        return Ok(());
+    } else if let Some(address) = &args.address {
+        use rpc::{BytecodeFetcher, RpcClient};
+        let client = RpcClient::new(&args.chain, args.rpc_url.as_deref())?;
+        client.get_code(address).await?
     } else {
         eprintln!("âŒ Provide --bytecode or --address, or use --server mode");
         return Ok(());
@@ -689,8 +1399,22 @@ async fn main() -> anyhow::Result<()> {
     println!("ğŸ“Š Analyzing {} bytes of bytecode...\n", bytecode.len());
     
     // Decompile
-    let output = Decompiler::decompile(&bytecode)?;
-    
+    let mut output = Decompiler::decompile(&bytecode)?;
+
+    // Optional signature resolution (opt-in so offline use still works).
+    if args.resolve {
+        output.resolved_signatures = resolve::resolve_functions(
+            &output.security.function_selectors,
+            args.signature_cache.as_deref(),
+        )
+        .await;
+        output.resolved_events = resolve::resolve_events(
+            &output.security.event_signatures,
+            args.signature_cache.as_deref(),
+        )
+        .await;
+    }
+
     match args.output.as_str() {
         "json" => {
             println!("{}", serde_json::to_string_pretty(&output)?);
@@ -705,10 +1429,28 @@ async fn main() -> anyhow::Result<()> {
             println!("ğŸ”¢ Instructions: {}", output.instruction_count);
             println!("ğŸ§± Basic Blocks: {}", output.block_count);
             println!("ğŸ“ˆ Complexity Score: {}", output.security.complexity_score);
+            println!("ğŸ“ˆ Estimated Gas Floor: {}", output.security.estimated_gas);
             
             println!("\nğŸ¯ Function Selectors:");
             for sel in &output.security.function_selectors {
-                println!("   {}", sel);
+                match output.resolved_signatures.get(sel) {
+                    Some(sigs) if !sigs.is_empty() => {
+                        println!("   {} â†’ {}", sel, sigs.join(", "));
+                    }
+                    _ => println!("   {}", sel),
+                }
+            }
+
+            if !output.security.event_signatures.is_empty() {
+                println!("\nğŸ“¡ Event Topics:");
+                for topic in &output.security.event_signatures {
+                    match output.resolved_events.get(topic) {
+                        Some(sigs) if !sigs.is_empty() => {
+                            println!("   {} â†’ {}", topic, sigs.join(", "));
+                        }
+                        _ => println!("   {}", topic),
+                    }
+                }
             }
             
             println!("\nâš ï¸  Risk Indicators:");
@@ -726,11 +1468,14 @@ async fn main() -> anyhow::Result<()> {
                 println!("\nğŸš¨ CRITICAL: Contract has SELFDESTRUCT capability!");
             }
         }
+        "graph" | "dot" => {
+            println!("{}", output.cfg.to_dot());
+        }
         _ => {
             eprintln!("Unknown output format: {}", args.output);
         }
     }
-    
+
     Ok(())
 }
 
@@ -765,10 +1510,60 @@ mod tests {
         let bytecode = vec![0xFF];
         let instructions = Disassembler::disassemble(&bytecode).unwrap();
         let analysis = SecurityAnalyzer::analyze(&instructions);
-        
+
         assert!(analysis.has_selfdestruct);
         assert!(!analysis.risk_indicators.is_empty());
     }
+
+    #[test]
+    fn test_resolved_jumpi_has_no_duplicate_edge() {
+        // PUSH1 0x04; JUMPI; STOP; JUMPDEST; STOP
+        let bytecode = vec![0x60, 0x04, 0x57, 0x00, 0x5b, 0x00];
+        let instructions = Disassembler::disassemble(&bytecode).unwrap();
+        let cfg = ControlFlowGraph::build(&instructions);
+
+        // The taken target (0x04) and the fall-through (0x03) must each appear
+        // exactly once despite build_edges and resolve_dynamic_jumps both
+        // running.
+        let mut succ = cfg.successors(0);
+        succ.sort_unstable();
+        assert_eq!(succ, vec![3, 4]);
+        assert!(cfg.unresolved_jumps.is_empty());
+    }
+
+    #[test]
+    fn test_dynamic_jump_left_unresolved() {
+        // PUSH1 0x00; CALLDATALOAD; JUMP  (target is runtime-dependent)
+        let bytecode = vec![0x60, 0x00, 0x35, 0x56];
+        let instructions = Disassembler::disassemble(&bytecode).unwrap();
+        let cfg = ControlFlowGraph::build(&instructions);
+
+        assert_eq!(cfg.unresolved_jumps, vec![0]);
+        assert!(cfg.successors(0).is_empty());
+    }
+
+    #[test]
+    fn test_gas_cost_tiers() {
+        // Storage/call tiers come from the hand-written model; cheap ops fall
+        // back to the generated base_gas table.
+        assert_eq!(Opcode::SSTORE.gas_cost(), 20000);
+        assert_eq!(Opcode::SLOAD.gas_cost(), 2100);
+        assert_eq!(Opcode::CALL.gas_cost(), 2600);
+        assert_eq!(Opcode::PUSH1.gas_cost(), Opcode::PUSH1.base_gas());
+    }
+
+    #[test]
+    fn test_estimated_gas_sums_opcodes() {
+        // PUSH1 0x00; PUSH1 0x00; SSTORE
+        let bytecode = vec![0x60, 0x00, 0x60, 0x00, 0x55];
+        let instructions = Disassembler::disassemble(&bytecode).unwrap();
+        let analysis = SecurityAnalyzer::analyze(&instructions);
+
+        let expected: u64 = instructions.iter().map(|i| i.opcode.gas_cost()).sum();
+        assert_eq!(analysis.estimated_gas, expected);
+        // The SSTORE dominates the floor.
+        assert!(analysis.estimated_gas >= 20000);
+    }
 }
 
 // Include extended test module
@@ -0,0 +1,150 @@
+/*
+ ═══════════════════════════════════════════════════════════════════════════════
+  SENTINEL SHIELD - Signature resolution
+
+  Turns bare 4-byte function selectors into candidate textual signatures such as
+  `transfer(address,uint256)` via a 4byte-style HTTP lookup, with an offline
+  cache file fallback so air-gapped use still works. Resolution is opt-in.
+ ═══════════════════════════════════════════════════════════════════════════════
+*/
+
+use std::collections::HashMap;
+use std::path::Path;
+
+use serde::Deserialize;
+
+use crate::Result;
+
+const FOURBYTE_FUNCTION_URL: &str = "https://www.4byte.directory/api/v1/signatures/";
+const FOURBYTE_EVENT_URL: &str = "https://www.4byte.directory/api/v1/event-signatures/";
+
+#[derive(Deserialize)]
+struct FourByteResponse {
+    results: Vec<FourByteResult>,
+}
+
+#[derive(Deserialize)]
+struct FourByteResult {
+    text_signature: String,
+}
+
+/// Resolve a set of 4-byte function selectors to candidate signatures.
+///
+/// The offline `cache` file (a JSON map of selector → signatures) is consulted
+/// first; only selectors missing from it are queried over HTTP. When `cache` is
+/// `None`, every selector is looked up online. Candidate lists are de-duplicated
+/// deterministically (sorted).
+pub async fn resolve_functions(
+    selectors: &[String],
+    cache: Option<&Path>,
+) -> HashMap<String, Vec<String>> {
+    resolve_with(selectors, cache, FOURBYTE_FUNCTION_URL).await
+}
+
+/// Resolve a set of 32-byte event topic0 hashes to candidate event signatures.
+pub async fn resolve_events(
+    topics: &[String],
+    cache: Option<&Path>,
+) -> HashMap<String, Vec<String>> {
+    resolve_with(topics, cache, FOURBYTE_EVENT_URL).await
+}
+
+pub(crate) async fn resolve_with(
+    hashes: &[String],
+    cache: Option<&Path>,
+    endpoint: &str,
+) -> HashMap<String, Vec<String>> {
+    let cached = cache.and_then(|p| load_cache(p).ok()).unwrap_or_default();
+    let client = reqwest::Client::new();
+    let mut out: HashMap<String, Vec<String>> = HashMap::new();
+
+    for hash in hashes {
+        if let Some(sigs) = cached.get(hash) {
+            out.insert(hash.clone(), dedupe(sigs.clone()));
+            continue;
+        }
+        if let Ok(sigs) = query(&client, endpoint, hash).await {
+            if !sigs.is_empty() {
+                out.insert(hash.clone(), dedupe(sigs));
+            }
+        }
+    }
+
+    out
+}
+
+async fn query(client: &reqwest::Client, endpoint: &str, hash: &str) -> Result<Vec<String>> {
+    let resp: FourByteResponse = client
+        .get(endpoint)
+        .query(&[("hex_signature", hash)])
+        .send()
+        .await
+        .map_err(|e| crate::DecompilerError::RpcError(e.to_string()))?
+        .json()
+        .await
+        .map_err(|e| crate::DecompilerError::RpcError(e.to_string()))?;
+    Ok(resp.results.into_iter().map(|r| r.text_signature).collect())
+}
+
+fn load_cache(path: &Path) -> Result<HashMap<String, Vec<String>>> {
+    let raw =
+        std::fs::read_to_string(path).map_err(|e| crate::DecompilerError::ParseError(e.to_string()))?;
+    serde_json::from_str(&raw).map_err(|e| crate::DecompilerError::ParseError(e.to_string()))
+}
+
+/// Sort and remove duplicate candidate signatures for stable output.
+fn dedupe(mut sigs: Vec<String>) -> Vec<String> {
+    sigs.sort();
+    sigs.dedup();
+    sigs
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn dedupe_sorts_and_removes_duplicates() {
+        let out = dedupe(vec![
+            "transfer(address,uint256)".to_string(),
+            "approve(address,uint256)".to_string(),
+            "transfer(address,uint256)".to_string(),
+        ]);
+        assert_eq!(
+            out,
+            vec![
+                "approve(address,uint256)".to_string(),
+                "transfer(address,uint256)".to_string(),
+            ]
+        );
+    }
+
+    #[test]
+    fn resolve_uses_cache_without_network() {
+        let mut cache: HashMap<String, Vec<String>> = HashMap::new();
+        cache.insert(
+            "0xa9059cbb".to_string(),
+            vec!["transfer(address,uint256)".to_string()],
+        );
+        let mut path = std::env::temp_dir();
+        path.push("sentinel_resolve_cache_test.json");
+        std::fs::write(&path, serde_json::to_string(&cache).unwrap()).unwrap();
+
+        let loaded = load_cache(&path).unwrap();
+        assert_eq!(
+            loaded.get("0xa9059cbb"),
+            Some(&vec!["transfer(address,uint256)".to_string()])
+        );
+
+        // The selector is in the cache, so resolution never reaches the network.
+        let resolved = tokio::runtime::Runtime::new()
+            .unwrap()
+            .block_on(resolve_functions(&["0xa9059cbb".to_string()], Some(path.as_path())));
+        assert_eq!(
+            resolved.get("0xa9059cbb"),
+            Some(&vec!["transfer(address,uint256)".to_string()])
+        );
+
+        let _ = std::fs::remove_file(&path);
+    }
+}
@@ -0,0 +1,135 @@
+/*
+ ═══════════════════════════════════════════════════════════════════════════════
+  SENTINEL SHIELD - JSON-RPC bytecode fetcher
+
+  Resolves a contract address to its deployed runtime bytecode via an
+  `eth_getCode` call. The client is modelled as a trait with an async method
+  and a blocking convenience wrapper so the CLI and the HTTP server can share
+  the same implementation.
+ ═══════════════════════════════════════════════════════════════════════════════
+*/
+
+use serde::{Deserialize, Serialize};
+
+use crate::{DecompilerError, Result};
+
+/// Default public JSON-RPC endpoints, keyed by the `--chain` value.
+const ENDPOINTS: &[(&str, &str)] = &[
+    ("ethereum", "https://eth.llamarpc.com"),
+    ("bsc", "https://bsc-dataseed.binance.org"),
+    ("polygon", "https://polygon-rpc.com"),
+    ("arbitrum", "https://arb1.arbitrum.io/rpc"),
+    ("optimism", "https://mainnet.optimism.io"),
+    ("base", "https://mainnet.base.org"),
+    ("avalanche", "https://api.avax.network/ext/bc/C/rpc"),
+];
+
+/// Resolve a chain name to its default RPC endpoint.
+pub fn endpoint_for_chain(chain: &str) -> Result<&'static str> {
+    ENDPOINTS
+        .iter()
+        .find(|(name, _)| name.eq_ignore_ascii_case(chain))
+        .map(|(_, url)| *url)
+        .ok_or_else(|| DecompilerError::RpcError(format!("unknown chain `{}`", chain)))
+}
+
+// ═══════════════════════════════════════════════════════════════════════════════
+//                              JSON-RPC WIRE TYPES
+// ═══════════════════════════════════════════════════════════════════════════════
+
+#[derive(Serialize)]
+struct RpcRequest<'a> {
+    jsonrpc: &'static str,
+    method: &'static str,
+    params: [&'a str; 2],
+    id: u32,
+}
+
+#[derive(Deserialize)]
+struct RpcResponse {
+    result: Option<String>,
+    error: Option<RpcErrorObject>,
+}
+
+#[derive(Deserialize)]
+struct RpcErrorObject {
+    #[allow(dead_code)]
+    code: i64,
+    message: String,
+}
+
+// ═══════════════════════════════════════════════════════════════════════════════
+//                              CLIENT
+// ═══════════════════════════════════════════════════════════════════════════════
+
+/// A source of on-chain runtime bytecode.
+pub trait BytecodeFetcher {
+    /// Fetch the runtime bytecode deployed at `address`.
+    fn get_code(
+        &self,
+        address: &str,
+    ) -> impl std::future::Future<Output = Result<Vec<u8>>> + Send;
+
+    /// Blocking convenience wrapper around [`get_code`](Self::get_code) for
+    /// synchronous call sites. It builds its own Tokio runtime, so it panics
+    /// when called from inside a running runtime (the async CLI and server
+    /// await `get_code` directly).
+    fn get_code_blocking(&self, address: &str) -> Result<Vec<u8>> {
+        tokio::runtime::Runtime::new()
+            .map_err(|e| DecompilerError::RpcError(e.to_string()))?
+            .block_on(self.get_code(address))
+    }
+}
+
+/// HTTP JSON-RPC client backed by `reqwest`.
+pub struct RpcClient {
+    url: String,
+    http: reqwest::Client,
+}
+
+impl RpcClient {
+    /// Build a client for a named chain, honouring an optional endpoint
+    /// override (a full custom RPC URL).
+    pub fn new(chain: &str, rpc_url: Option<&str>) -> Result<Self> {
+        let url = match rpc_url {
+            Some(u) => u.to_string(),
+            None => endpoint_for_chain(chain)?.to_string(),
+        };
+        Ok(Self {
+            url,
+            http: reqwest::Client::new(),
+        })
+    }
+}
+
+impl BytecodeFetcher for RpcClient {
+    async fn get_code(&self, address: &str) -> Result<Vec<u8>> {
+        let request = RpcRequest {
+            jsonrpc: "2.0",
+            method: "eth_getCode",
+            params: [address, "latest"],
+            id: 1,
+        };
+
+        let response: RpcResponse = self
+            .http
+            .post(&self.url)
+            .json(&request)
+            .send()
+            .await
+            .map_err(|e| DecompilerError::RpcError(e.to_string()))?
+            .json()
+            .await
+            .map_err(|e| DecompilerError::RpcError(e.to_string()))?;
+
+        if let Some(err) = response.error {
+            return Err(DecompilerError::RpcError(err.message));
+        }
+
+        let hex_code = response
+            .result
+            .ok_or_else(|| DecompilerError::RpcError("missing result in RPC response".into()))?;
+        let clean = hex_code.strip_prefix("0x").unwrap_or(&hex_code);
+        hex::decode(clean).map_err(|e| DecompilerError::InvalidBytecode(e.to_string()))
+    }
+}
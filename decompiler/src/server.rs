@@ -8,7 +8,7 @@
 */
 
 use axum::{
-    extract::Json,
+    extract::{Json, Path, State},
     http::StatusCode,
     response::IntoResponse,
     routing::{get, post},
@@ -19,6 +19,7 @@ use tower_http::cors::{Any, CorsLayer};
 use std::net::SocketAddr;
 
 use crate::{Disassembler, SecurityAnalyzer, ControlFlowGraph};
+use crate::rpc::{BytecodeFetcher, RpcClient};
 
 // ═══════════════════════════════════════════════════════════════════════════════
 //                              REQUEST/RESPONSE TYPES
@@ -29,31 +30,103 @@ pub struct AnalyzeRequest {
     pub bytecode: String,
 }
 
-#[derive(Debug, Serialize)]
+#[derive(Debug, Clone, Serialize)]
 pub struct AnalyzeResponse {
     pub success: bool,
     pub opcodes: Vec<String>,
-    pub functions: Vec<String>,
+    pub functions: Vec<crate::cfg::FunctionInfo>,
     pub selectors: Vec<String>,
     pub is_proxy: bool,
     pub has_sstore: bool,
     pub has_call: bool,
     pub has_delegatecall: bool,
     pub has_selfdestruct: bool,
-    pub complexity: i32,
+    pub complexity: u32,
+    pub estimated_gas: u64,
     pub warnings: Vec<String>,
     pub risk_indicators: Vec<RiskIndicator>,
     pub instruction_count: usize,
     pub block_count: usize,
+    pub unresolved_jumps: usize,
 }
 
-#[derive(Debug, Serialize)]
+#[derive(Debug, Clone, Serialize)]
 pub struct RiskIndicator {
     pub name: String,
     pub severity: String,
     pub description: String,
 }
 
+#[derive(Debug, Deserialize)]
+pub struct BatchRequest {
+    pub items: Vec<BatchItem>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct BatchItem {
+    pub id: String,
+    pub bytecode: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct BatchResultItem {
+    pub id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub result: Option<AnalyzeResponse>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct JobCreated {
+    pub job_id: String,
+    pub status: String,
+}
+
+/// Snapshot of an async job returned by the polling endpoint.
+#[derive(Debug, Serialize)]
+pub struct JobStatus {
+    pub status: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub result: Option<AnalyzeResponse>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+/// A submitted async analysis job: pending until the background task finishes,
+/// then either a completed response or an error string.
+enum Job {
+    Pending,
+    Done(Box<AnalyzeResponse>),
+    Failed(String),
+}
+
+/// Shared server state: the async job table and a monotonically increasing id
+/// counter.
+#[derive(Clone, Default)]
+pub struct AppState {
+    jobs: std::sync::Arc<tokio::sync::Mutex<std::collections::HashMap<u64, Job>>>,
+    next_id: std::sync::Arc<std::sync::atomic::AtomicU64>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct AddressRequest {
+    pub address: String,
+    #[serde(default)]
+    pub rpc_url: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct CfgRequest {
+    pub bytecode: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct CfgResponse {
+    pub dot: String,
+    pub block_count: usize,
+}
+
 #[derive(Debug, Serialize)]
 pub struct HealthResponse {
     pub status: String,
@@ -79,50 +152,47 @@ async fn health_handler() -> impl IntoResponse {
     })
 }
 
-async fn analyze_handler(Json(payload): Json<AnalyzeRequest>) -> impl IntoResponse {
-    // Parse bytecode
-    let bytecode_str = payload.bytecode.trim_start_matches("0x");
-    
-    let bytecode = match hex::decode(bytecode_str) {
-        Ok(b) => b,
-        Err(e) => {
-            return (
-                StatusCode::BAD_REQUEST,
-                Json(ErrorResponse {
-                    error: "Invalid bytecode".to_string(),
-                    details: Some(e.to_string()),
-                }),
-            ).into_response();
-        }
-    };
+/// Run the full disassembly → CFG → security pipeline over a hex bytecode
+/// string, producing either a populated [`AnalyzeResponse`] or a
+/// status-tagged [`ErrorResponse`]. Shared by the synchronous, batch, and
+/// async handlers so they agree on validation and output shape.
+pub(crate) fn analyze_core(bytecode: &str) -> Result<AnalyzeResponse, (StatusCode, ErrorResponse)> {
+    let bytecode_str = bytecode.trim_start_matches("0x");
+
+    let bytecode = hex::decode(bytecode_str).map_err(|e| {
+        (
+            StatusCode::BAD_REQUEST,
+            ErrorResponse {
+                error: "Invalid bytecode".to_string(),
+                details: Some(e.to_string()),
+            },
+        )
+    })?;
 
     if bytecode.is_empty() {
-        return (
+        return Err((
             StatusCode::BAD_REQUEST,
-            Json(ErrorResponse {
+            ErrorResponse {
                 error: "Empty bytecode".to_string(),
                 details: None,
-            }),
-        ).into_response();
+            },
+        ));
     }
 
     // Disassemble
-    let instructions = match Disassembler::disassemble(&bytecode) {
-        Ok(i) => i,
-        Err(e) => {
-            return (
-                StatusCode::INTERNAL_SERVER_ERROR,
-                Json(ErrorResponse {
-                    error: "Disassembly failed".to_string(),
-                    details: Some(e.to_string()),
-                }),
-            ).into_response();
-        }
-    };
+    let instructions = Disassembler::disassemble(&bytecode).map_err(|e| {
+        (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            ErrorResponse {
+                error: "Disassembly failed".to_string(),
+                details: Some(e.to_string()),
+            },
+        )
+    })?;
 
     // Build CFG
     let cfg = ControlFlowGraph::build(&instructions);
-    
+
     // Security analysis
     let security = SecurityAnalyzer::analyze(&instructions);
 
@@ -157,24 +227,255 @@ async fn analyze_handler(Json(payload): Json<AnalyzeRequest>) -> impl IntoRespon
         warnings.push("Contract uses deprecated CALLCODE opcode".to_string());
     }
 
-    let response = AnalyzeResponse {
+    Ok(AnalyzeResponse {
         success: true,
         opcodes,
-        functions: vec![], // TODO: Extract function boundaries
+        functions: crate::cfg::function_infos(&instructions, &cfg),
         selectors: security.function_selectors.clone(),
         is_proxy: security.has_delegatecall,
-        has_sstore: security.has_sstore,
-        has_call: security.has_external_call,
+        has_sstore: security.storage_writes > 0,
+        has_call: security.external_calls > 0,
         has_delegatecall: security.has_delegatecall,
         has_selfdestruct: security.has_selfdestruct,
         complexity: security.complexity_score,
+        estimated_gas: security.estimated_gas,
         warnings,
         risk_indicators,
         instruction_count: instructions.len(),
         block_count: cfg.graph.node_count(),
+        unresolved_jumps: cfg.unresolved_jumps.len(),
+    })
+}
+
+async fn analyze_handler(Json(payload): Json<AnalyzeRequest>) -> impl IntoResponse {
+    match analyze_core(&payload.bytecode) {
+        Ok(response) => Json(response).into_response(),
+        Err((status, err)) => (status, Json(err)).into_response(),
+    }
+}
+
+/// Build the control-flow graph for the supplied bytecode and return it as
+/// Graphviz DOT, with dangerous blocks highlighted.
+async fn cfg_handler(Json(payload): Json<CfgRequest>) -> impl IntoResponse {
+    let bytecode_str = payload.bytecode.trim_start_matches("0x");
+    let bytecode = match hex::decode(bytecode_str) {
+        Ok(b) => b,
+        Err(e) => {
+            return (
+                StatusCode::BAD_REQUEST,
+                Json(ErrorResponse {
+                    error: "Invalid bytecode".to_string(),
+                    details: Some(e.to_string()),
+                }),
+            )
+                .into_response();
+        }
+    };
+
+    let instructions = match Disassembler::disassemble(&bytecode) {
+        Ok(i) => i,
+        Err(e) => {
+            return (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ErrorResponse {
+                    error: "Disassembly failed".to_string(),
+                    details: Some(e.to_string()),
+                }),
+            )
+                .into_response();
+        }
+    };
+
+    let cfg = ControlFlowGraph::build(&instructions);
+    Json(CfgResponse {
+        dot: cfg.to_dot(true),
+        block_count: cfg.graph.node_count(),
+    })
+    .into_response()
+}
+
+/// Analyze many bytecodes in one request. The CPU-bound pipeline is dispatched
+/// across `spawn_blocking` tasks, bounded by a semaphore so a large batch does
+/// not swamp the blocking thread pool. Results preserve input order.
+async fn batch_handler(Json(payload): Json<BatchRequest>) -> impl IntoResponse {
+    const MAX_CONCURRENCY: usize = 8;
+    let semaphore = std::sync::Arc::new(tokio::sync::Semaphore::new(MAX_CONCURRENCY));
+
+    let mut handles = Vec::with_capacity(payload.items.len());
+    for item in payload.items {
+        let semaphore = semaphore.clone();
+        handles.push(tokio::spawn(async move {
+            // Held for the duration of the blocking analysis.
+            let _permit = semaphore.acquire_owned().await.expect("semaphore closed");
+            let BatchItem { id, bytecode } = item;
+            let outcome = tokio::task::spawn_blocking(move || analyze_core(&bytecode)).await;
+            match outcome {
+                Ok(Ok(result)) => BatchResultItem {
+                    id,
+                    result: Some(result),
+                    error: None,
+                },
+                Ok(Err((_, err))) => BatchResultItem {
+                    id,
+                    result: None,
+                    error: Some(err.error),
+                },
+                Err(e) => BatchResultItem {
+                    id,
+                    result: None,
+                    error: Some(format!("analysis task failed: {}", e)),
+                },
+            }
+        }));
+    }
+
+    let mut results = Vec::with_capacity(handles.len());
+    for handle in handles {
+        match handle.await {
+            Ok(item) => results.push(item),
+            Err(e) => results.push(BatchResultItem {
+                id: String::new(),
+                result: None,
+                error: Some(format!("analysis task panicked: {}", e)),
+            }),
+        }
+    }
+
+    Json(results).into_response()
+}
+
+/// Submit a bytecode for asynchronous analysis. Returns a job id immediately and
+/// runs the pipeline on a background task; poll [`async_status_handler`] for the
+/// result.
+async fn async_submit_handler(
+    State(state): State<AppState>,
+    Json(payload): Json<AnalyzeRequest>,
+) -> impl IntoResponse {
+    let id = state
+        .next_id
+        .fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+    state.jobs.lock().await.insert(id, Job::Pending);
+
+    let jobs = state.jobs.clone();
+    let bytecode = payload.bytecode;
+    tokio::spawn(async move {
+        let outcome = tokio::task::spawn_blocking(move || analyze_core(&bytecode)).await;
+        let job = match outcome {
+            Ok(Ok(result)) => Job::Done(Box::new(result)),
+            Ok(Err((_, err))) => Job::Failed(err.error),
+            Err(e) => Job::Failed(format!("analysis task failed: {}", e)),
+        };
+        jobs.lock().await.insert(id, job);
+    });
+
+    (
+        StatusCode::ACCEPTED,
+        Json(JobCreated {
+            job_id: id.to_string(),
+            status: "pending".to_string(),
+        }),
+    )
+        .into_response()
+}
+
+/// Poll an async analysis job by id.
+async fn async_status_handler(
+    State(state): State<AppState>,
+    Path(job_id): Path<String>,
+) -> impl IntoResponse {
+    let id: u64 = match job_id.parse() {
+        Ok(id) => id,
+        Err(_) => {
+            return (
+                StatusCode::BAD_REQUEST,
+                Json(ErrorResponse {
+                    error: "Invalid job id".to_string(),
+                    details: None,
+                }),
+            )
+                .into_response();
+        }
     };
 
-    Json(response).into_response()
+    let jobs = state.jobs.lock().await;
+    match jobs.get(&id) {
+        None => (
+            StatusCode::NOT_FOUND,
+            Json(ErrorResponse {
+                error: "Unknown job id".to_string(),
+                details: None,
+            }),
+        )
+            .into_response(),
+        Some(Job::Pending) => Json(JobStatus {
+            status: "pending".to_string(),
+            result: None,
+            error: None,
+        })
+        .into_response(),
+        Some(Job::Done(result)) => Json(JobStatus {
+            status: "done".to_string(),
+            result: Some((**result).clone()),
+            error: None,
+        })
+        .into_response(),
+        Some(Job::Failed(err)) => Json(JobStatus {
+            status: "failed".to_string(),
+            result: None,
+            error: Some(err.clone()),
+        })
+        .into_response(),
+    }
+}
+
+/// Fetch the runtime bytecode deployed at an address via `eth_getCode` and run
+/// it through the standard analysis pipeline. An empty result (an EOA or a
+/// self-destructed contract) is reported as a clear error rather than an empty
+/// analysis.
+async fn address_handler(Json(payload): Json<AddressRequest>) -> impl IntoResponse {
+    let client = match RpcClient::new("ethereum", payload.rpc_url.as_deref()) {
+        Ok(c) => c,
+        Err(e) => {
+            return (
+                StatusCode::BAD_REQUEST,
+                Json(ErrorResponse {
+                    error: "Invalid RPC configuration".to_string(),
+                    details: Some(e.to_string()),
+                }),
+            )
+                .into_response();
+        }
+    };
+
+    let code = match client.get_code(&payload.address).await {
+        Ok(c) => c,
+        Err(e) => {
+            return (
+                StatusCode::BAD_GATEWAY,
+                Json(ErrorResponse {
+                    error: "Failed to fetch on-chain bytecode".to_string(),
+                    details: Some(e.to_string()),
+                }),
+            )
+                .into_response();
+        }
+    };
+
+    if code.is_empty() {
+        return (
+            StatusCode::UNPROCESSABLE_ENTITY,
+            Json(ErrorResponse {
+                error: "No code at address (EOA or self-destructed contract)".to_string(),
+                details: None,
+            }),
+        )
+            .into_response();
+    }
+
+    match analyze_core(&hex::encode(&code)) {
+        Ok(response) => Json(response).into_response(),
+        Err((status, err)) => (status, Json(err)).into_response(),
+    }
 }
 
 // ═══════════════════════════════════════════════════════════════════════════════
@@ -187,10 +488,18 @@ pub async fn run_server(port: u16) -> Result<(), Box<dyn std::error::Error>> {
         .allow_methods(Any)
         .allow_headers(Any);
 
+    let state = AppState::default();
+
     let app = Router::new()
         .route("/health", get(health_handler))
         .route("/analyze", post(analyze_handler))
-        .layer(cors);
+        .route("/analyze/batch", post(batch_handler))
+        .route("/analyze/async", post(async_submit_handler))
+        .route("/analyze/async/:id", get(async_status_handler))
+        .route("/analyze/address", post(address_handler))
+        .route("/cfg", post(cfg_handler))
+        .layer(cors)
+        .with_state(state);
 
     let addr = SocketAddr::from(([0, 0, 0, 0], port));
     
@@ -0,0 +1,230 @@
+/*
+ ═══════════════════════════════════════════════════════════════════════════════
+  SENTINEL SHIELD - Symbolic stack / operand provenance
+
+  A lightweight abstract interpreter that walks each basic block tracking where
+  each stack value came from. Knowing the provenance of a dangerous opcode's
+  address/value operand lets the analyzer tell, say, a SELFDESTRUCT guarded by a
+  constant address apart from one whose beneficiary is `CALLER`-derived.
+ ═══════════════════════════════════════════════════════════════════════════════
+*/
+
+use std::collections::HashMap;
+
+use crate::{Instruction, Opcode};
+
+/// Where a stack value came from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Provenance {
+    /// A literal pushed onto the stack (e.g. a `PUSHn` immediate).
+    Constant(Vec<u8>),
+    /// Derived from calldata at the given offset.
+    CallData(u64),
+    /// Derived from an environment opcode such as `CALLER`/`ORIGIN`/`ADDRESS`.
+    Env(Opcode),
+    /// Opaque / runtime-dependent.
+    Unknown,
+}
+
+impl Provenance {
+    /// Human-readable tag for risk descriptions.
+    pub fn describe(&self) -> String {
+        match self {
+            Provenance::Constant(bytes) => format!("constant 0x{}", hex::encode(bytes)),
+            Provenance::CallData(off) => format!("calldata@{}", off),
+            Provenance::Env(op) => format!("{:?}", op),
+            Provenance::Unknown => "unknown".to_string(),
+        }
+    }
+
+    /// Whether this value is derived from the caller/origin — an attacker
+    /// influenceable source.
+    pub fn is_caller_derived(&self) -> bool {
+        matches!(self, Provenance::Env(Opcode::CALLER) | Provenance::Env(Opcode::ORIGIN))
+    }
+}
+
+/// Compute, for each dangerous opcode, the provenance of its primary
+/// address/beneficiary operand (the address for the `CALL` family, the
+/// beneficiary for `SELFDESTRUCT`), keyed by the opcode's byte offset.
+///
+/// The instructions are walked in order with a single stack; a `JUMPDEST` acts
+/// as a merge point and conservatively clears the stack (values popped below
+/// it read as `Unknown`), which keeps the pass terminating without a
+/// fixed-point iteration. Other block boundaries do not reset the stack.
+pub fn operand_provenance(instructions: &[Instruction]) -> HashMap<usize, Provenance> {
+    let mut stack: Vec<Provenance> = Vec::new();
+    let mut out = HashMap::new();
+
+    for instr in instructions {
+        match instr.opcode {
+            // Merge point: be conservative.
+            Opcode::JUMPDEST => stack.clear(),
+
+            Opcode::SELFDESTRUCT => {
+                out.insert(instr.offset, top(&stack));
+            }
+            Opcode::CALL | Opcode::CALLCODE => {
+                // stack: gas, addr, value, ...
+                out.insert(instr.offset, nth(&stack, 1));
+            }
+            Opcode::DELEGATECALL | Opcode::STATICCALL => {
+                // stack: gas, addr, ...
+                out.insert(instr.offset, nth(&stack, 1));
+            }
+            _ => {}
+        }
+
+        step(&mut stack, instr);
+    }
+
+    out
+}
+
+/// Apply one instruction to the symbolic stack.
+fn step(stack: &mut Vec<Provenance>, instr: &Instruction) {
+    let op = instr.opcode;
+    match op {
+        _ if op.category() == "push" => {
+            let bytes = instr.argument.clone().unwrap_or_default();
+            stack.push(Provenance::Constant(bytes));
+        }
+        Opcode::CALLDATALOAD => {
+            let offset = match pop(stack) {
+                Provenance::Constant(b) => be_u64(&b),
+                _ => 0,
+            };
+            stack.push(Provenance::CallData(offset));
+        }
+        Opcode::CALLER | Opcode::ORIGIN | Opcode::ADDRESS | Opcode::CALLVALUE => {
+            stack.push(Provenance::Env(op));
+        }
+        Opcode::ADD | Opcode::SUB | Opcode::MUL | Opcode::DIV | Opcode::AND | Opcode::OR
+        | Opcode::XOR => {
+            let a = pop(stack);
+            let b = pop(stack);
+            stack.push(fold(op, &a, &b));
+        }
+        _ if op.category() == "dup" => {
+            let n = (instr.raw_byte - 0x80 + 1) as usize;
+            stack.push(nth(stack, n - 1));
+        }
+        _ if op.category() == "swap" => {
+            let n = (instr.raw_byte - 0x90 + 1) as usize;
+            let len = stack.len();
+            if len > n {
+                stack.swap(len - 1, len - 1 - n);
+            }
+        }
+        _ => {
+            for _ in 0..op.stack_inputs() {
+                pop(stack);
+            }
+            for _ in 0..op.stack_outputs() {
+                stack.push(Provenance::Unknown);
+            }
+        }
+    }
+}
+
+/// Constant-fold simple binary arithmetic when both operands are known.
+fn fold(op: Opcode, a: &Provenance, b: &Provenance) -> Provenance {
+    if let (Provenance::Constant(x), Provenance::Constant(y)) = (a, b) {
+        if x.len() <= 16 && y.len() <= 16 {
+            let (x, y) = (be_u128(x), be_u128(y));
+            let r = match op {
+                Opcode::ADD => x.wrapping_add(y),
+                Opcode::SUB => x.wrapping_sub(y),
+                Opcode::MUL => x.wrapping_mul(y),
+                Opcode::DIV => {
+                    if y == 0 {
+                        0
+                    } else {
+                        x / y
+                    }
+                }
+                Opcode::AND => x & y,
+                Opcode::OR => x | y,
+                Opcode::XOR => x ^ y,
+                _ => return Provenance::Unknown,
+            };
+            return Provenance::Constant(trim_leading_zeros(r.to_be_bytes().to_vec()));
+        }
+    }
+    Provenance::Unknown
+}
+
+fn pop(stack: &mut Vec<Provenance>) -> Provenance {
+    stack.pop().unwrap_or(Provenance::Unknown)
+}
+
+fn top(stack: &[Provenance]) -> Provenance {
+    stack.last().cloned().unwrap_or(Provenance::Unknown)
+}
+
+/// The `n`-th item from the top (0-indexed).
+fn nth(stack: &[Provenance], n: usize) -> Provenance {
+    stack
+        .len()
+        .checked_sub(n + 1)
+        .map(|i| stack[i].clone())
+        .unwrap_or(Provenance::Unknown)
+}
+
+fn be_u64(bytes: &[u8]) -> u64 {
+    let mut arr = [0u8; 8];
+    let take = bytes.len().min(8);
+    arr[8 - take..].copy_from_slice(&bytes[bytes.len() - take..]);
+    u64::from_be_bytes(arr)
+}
+
+fn be_u128(bytes: &[u8]) -> u128 {
+    let mut arr = [0u8; 16];
+    let take = bytes.len().min(16);
+    arr[16 - take..].copy_from_slice(&bytes[bytes.len() - take..]);
+    u128::from_be_bytes(arr)
+}
+
+fn trim_leading_zeros(mut bytes: Vec<u8>) -> Vec<u8> {
+    while bytes.len() > 1 && bytes[0] == 0 {
+        bytes.remove(0);
+    }
+    bytes
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Disassembler;
+
+    #[test]
+    fn delegatecall_target_is_caller_derived() {
+        // CALLER (the address operand); PUSH1 gas; DELEGATECALL
+        let instructions = Disassembler::disassemble(&[0x33, 0x60, 0x00, 0xF4]).unwrap();
+        let prov = operand_provenance(&instructions);
+        let target = prov.get(&3).expect("delegatecall provenance recorded");
+        assert_eq!(*target, Provenance::Env(Opcode::CALLER));
+        assert!(target.is_caller_derived());
+    }
+
+    #[test]
+    fn selfdestruct_target_constant() {
+        // PUSH1 0xbe (beneficiary); SELFDESTRUCT
+        let instructions = Disassembler::disassemble(&[0x60, 0xbe, 0xFF]).unwrap();
+        let prov = operand_provenance(&instructions);
+        let target = prov.get(&2).expect("selfdestruct provenance recorded");
+        assert_eq!(*target, Provenance::Constant(vec![0xbe]));
+        assert!(!target.is_caller_derived());
+    }
+
+    #[test]
+    fn constant_arithmetic_folds() {
+        // PUSH1 0x02; PUSH1 0x03; ADD  →  constant 0x05
+        let instructions = Disassembler::disassemble(&[0x60, 0x02, 0x60, 0x03, 0x01]).unwrap();
+        let mut stack: Vec<Provenance> = Vec::new();
+        for instr in &instructions {
+            step(&mut stack, instr);
+        }
+        assert_eq!(stack.last(), Some(&Provenance::Constant(vec![0x05])));
+    }
+}
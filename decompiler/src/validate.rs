@@ -0,0 +1,219 @@
+/*
+ ═══════════════════════════════════════════════════════════════════════════════
+  SENTINEL SHIELD - Differential validation harness
+
+  Ingests a directory of fixtures (hex bytecode plus an expected-instructions
+  JSON, in the style of EVM state-test suites) and asserts the decompiler
+  reproduces them: instruction count, per-offset opcode/argument, block
+  boundaries, and detected function selectors. Mismatches are reported as an
+  expected-vs-got diff with a pass/fail summary.
+ ═══════════════════════════════════════════════════════════════════════════════
+*/
+
+use std::fs;
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    ControlFlowGraph, Disassembler, Instruction, Result, SecurityAnalyzer,
+};
+
+/// One opcode of the expected disassembly.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ExpectedInstruction {
+    pub offset: usize,
+    pub opcode: String,
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub argument: Option<String>,
+}
+
+/// A single fixture: the input bytecode and the facts the decompiler must
+/// reproduce.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Fixture {
+    pub bytecode: String,
+    pub instructions: Vec<ExpectedInstruction>,
+    pub block_starts: Vec<usize>,
+    pub selectors: Vec<String>,
+}
+
+impl Fixture {
+    /// Capture a fixture from raw bytecode, so the corpus can grow as new
+    /// opcodes are added to the table.
+    pub fn generate(bytecode: &[u8]) -> Result<Self> {
+        let instructions = Disassembler::disassemble(bytecode)?;
+        let cfg = ControlFlowGraph::build(&instructions);
+        let security = SecurityAnalyzer::analyze(&instructions);
+
+        let mut block_starts: Vec<usize> = cfg.blocks.keys().copied().collect();
+        block_starts.sort_unstable();
+
+        Ok(Fixture {
+            bytecode: format!("0x{}", hex::encode(bytecode)),
+            instructions: instructions.iter().map(expected_of).collect(),
+            block_starts,
+            selectors: security.function_selectors,
+        })
+    }
+}
+
+fn expected_of(instr: &Instruction) -> ExpectedInstruction {
+    ExpectedInstruction {
+        offset: instr.offset,
+        opcode: format!("{:?}", instr.opcode),
+        argument: instr.argument.as_ref().map(|b| format!("0x{}", hex::encode(b))),
+    }
+}
+
+/// Outcome of validating a whole corpus.
+#[derive(Debug, Default)]
+pub struct Report {
+    pub passed: usize,
+    pub failed: usize,
+    pub skipped: usize,
+    pub mismatches: Vec<String>,
+}
+
+impl Report {
+    pub fn ok(&self) -> bool {
+        self.failed == 0
+    }
+}
+
+/// Validate every `*.json` fixture in `dir`, skipping any file whose name
+/// appears in `skip`.
+pub fn run(dir: &Path, skip: &[String]) -> Result<Report> {
+    let mut report = Report::default();
+
+    let mut entries: Vec<_> = fs::read_dir(dir)
+        .map_err(|e| crate::DecompilerError::ParseError(e.to_string()))?
+        .filter_map(|e| e.ok())
+        .map(|e| e.path())
+        .filter(|p| p.extension().map(|x| x == "json").unwrap_or(false))
+        .collect();
+    entries.sort();
+
+    for path in entries {
+        let name = path
+            .file_name()
+            .and_then(|n| n.to_str())
+            .unwrap_or_default()
+            .to_string();
+        if skip.iter().any(|s| s == &name) {
+            report.skipped += 1;
+            continue;
+        }
+
+        let raw = fs::read_to_string(&path)
+            .map_err(|e| crate::DecompilerError::ParseError(e.to_string()))?;
+        let fixture: Fixture = serde_json::from_str(&raw)
+            .map_err(|e| crate::DecompilerError::ParseError(e.to_string()))?;
+
+        match check(&fixture) {
+            Ok(()) => report.passed += 1,
+            Err(diffs) => {
+                report.failed += 1;
+                for d in diffs {
+                    report.mismatches.push(format!("{}: {}", name, d));
+                }
+            }
+        }
+    }
+
+    Ok(report)
+}
+
+/// Compare one fixture against freshly computed output, collecting every
+/// expected-vs-got mismatch.
+fn check(fixture: &Fixture) -> std::result::Result<(), Vec<String>> {
+    let clean = fixture.bytecode.strip_prefix("0x").unwrap_or(&fixture.bytecode);
+    let bytecode = match hex::decode(clean) {
+        Ok(b) => b,
+        Err(e) => return Err(vec![format!("invalid fixture bytecode: {}", e)]),
+    };
+
+    let instructions = match Disassembler::disassemble(&bytecode) {
+        Ok(i) => i,
+        Err(e) => return Err(vec![format!("disassembly failed: {}", e)]),
+    };
+    let cfg = ControlFlowGraph::build(&instructions);
+    let security = SecurityAnalyzer::analyze(&instructions);
+
+    let mut diffs = Vec::new();
+
+    if instructions.len() != fixture.instructions.len() {
+        diffs.push(format!(
+            "instruction count: expected {}, got {}",
+            fixture.instructions.len(),
+            instructions.len()
+        ));
+    }
+
+    for (exp, got) in fixture.instructions.iter().zip(instructions.iter()) {
+        let got = expected_of(got);
+        if &got != exp {
+            diffs.push(format!("at offset {}: expected {:?}, got {:?}", exp.offset, exp, got));
+        }
+    }
+
+    let mut got_starts: Vec<usize> = cfg.blocks.keys().copied().collect();
+    got_starts.sort_unstable();
+    if got_starts != fixture.block_starts {
+        diffs.push(format!(
+            "block boundaries: expected {:?}, got {:?}",
+            fixture.block_starts, got_starts
+        ));
+    }
+
+    if security.function_selectors != fixture.selectors {
+        diffs.push(format!(
+            "selectors: expected {:?}, got {:?}",
+            fixture.selectors, security.function_selectors
+        ));
+    }
+
+    if diffs.is_empty() {
+        Ok(())
+    } else {
+        Err(diffs)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    fn fixtures_dir() -> PathBuf {
+        PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures")
+    }
+
+    #[test]
+    fn corpus_reproduces() {
+        let report = run(&fixtures_dir(), &[]).expect("fixtures directory readable");
+        assert!(
+            report.ok(),
+            "fixture corpus mismatched: {:?}",
+            report.mismatches
+        );
+        assert!(report.passed >= 2, "expected every fixture to pass");
+    }
+
+    #[test]
+    fn skip_excludes_named_fixture() {
+        let report = run(&fixtures_dir(), &["selector_dispatch.json".to_string()])
+            .expect("fixtures directory readable");
+        assert_eq!(report.skipped, 1);
+    }
+
+    #[test]
+    fn generated_fixture_round_trips() {
+        // PUSH4 selector; EQ; PUSH1 dest; JUMPI; STOP; JUMPDEST; STOP
+        let bytecode = vec![
+            0x63, 0x12, 0x34, 0x56, 0x78, 0x14, 0x60, 0x0a, 0x57, 0x00, 0x5b, 0x00,
+        ];
+        let fixture = Fixture::generate(&bytecode).expect("generate fixture");
+        assert_eq!(check(&fixture), Ok(()));
+    }
+}